@@ -0,0 +1,58 @@
+use std::io;
+use std::io::Write;
+
+use crate::engine::Glass;
+use crate::errorsystem::error_type::ErrorType;
+use crate::errorsystem::GlassError;
+use crate::lang::interpreter::primitive::native_function::{Arity, NativeResult};
+use crate::lang::interpreter::primitive::{Primitive, Type};
+
+// the functions every glass session starts with, registered the same way a host program registers its own
+pub fn register(glass: &mut Glass) {
+    glass.register_function("print", Arity::AtLeast(0), print);
+    glass.register_function("println", Arity::AtLeast(0), println);
+    glass.register_function("typeof", Arity::Exact(1), type_of);
+    glass.register_function("len", Arity::Exact(1), len);
+}
+
+// the arguments are printed separated by spaces
+fn join(args: &[Primitive]) -> String {
+    args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>().join(" ")
+}
+
+// print! and println! panic when stdout is closed, so failed writes are raised as errors instead
+fn write_out(text: &str) -> NativeResult {
+    let mut stdout = io::stdout().lock();
+
+    match stdout.write_all(text.as_bytes()).and_then(|_| stdout.flush()) {
+        Ok(_) => Ok(Primitive::Void),
+        Err(error) => Err(GlassError::new(ErrorType::WriteFailed(error.kind()), None)),
+    }
+}
+
+fn print(args: Vec<Primitive>) -> NativeResult {
+    write_out(&join(&args))
+}
+
+fn println(args: Vec<Primitive>) -> NativeResult {
+    write_out(&format!("{}\n", join(&args)))
+}
+
+fn type_of(args: Vec<Primitive>) -> NativeResult {
+    Ok(Primitive::TypePrimitive(args[0].get_type()))
+}
+
+// strings are measured in characters, not bytes
+fn len(args: Vec<Primitive>) -> NativeResult {
+    let length = match &args[0] {
+        Primitive::String(value) => value.chars().count(),
+        Primitive::List(items) => items.borrow().len(),
+        Primitive::Dictionary(entries) => entries.borrow().len(),
+        value => {
+            let error = GlassError::new(ErrorType::UnexpectedType(Type::List.get_name(), value.get_type().get_name()), None);
+            return Err(error.with_note(String::from("len takes a str, list or dict")));
+        }
+    };
+
+    Ok(Primitive::Number(length as f64))
+}
@@ -0,0 +1,110 @@
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+
+use crate::errorsystem::error_type::ErrorType;
+use crate::errorsystem::raise_error;
+use crate::errorsystem::GlassError;
+use crate::lang::interpreter::{DEFAULT_MAX_DEPTH, Interpreter};
+use crate::lang::interpreter::primitive::native_function::{Arity, NativeFunction, NativeResult};
+use crate::lang::interpreter::primitive::Primitive;
+use crate::lang::lexer::Lexer;
+use crate::lang::parser::{DEFAULT_MAX_ERRORS, Parser};
+
+mod builtins;
+
+const STRING_FILENAME: &str = "<string>";
+
+// an embeddable glass session. every evaluation runs in the same global scope, so variables and
+// functions defined by one are visible to the next and to the host through get_global and call_function
+pub struct Glass {
+    interpreter: Interpreter,
+    max_errors: usize,
+}
+
+impl Glass {
+    pub fn new() -> Self {
+        Self::with_limits(DEFAULT_MAX_ERRORS, DEFAULT_MAX_DEPTH)
+    }
+
+    // max_errors is the most syntax errors reported per evaluation, max_depth the deepest glass
+    // function calls can be nested
+    pub fn with_limits(max_errors: usize, max_depth: usize) -> Self {
+        let mut glass = Glass {
+            interpreter: Interpreter::new(None, max_depth),
+            max_errors,
+        };
+
+        builtins::register(&mut glass);
+        glass
+    }
+
+    // errors are reported against the filename "<string>". an empty string is an empty program rather
+    // than the empty file error the lexer raises, so it evaluates to void
+    pub fn eval_str(&mut self, source: &str) -> Result<Primitive, Vec<GlassError>> {
+        if source.is_empty() {
+            return Ok(Primitive::Void);
+        }
+
+        self.eval_source(STRING_FILENAME, source)
+    }
+
+    pub fn eval_file<P: AsRef<Path>>(&mut self, path: P) -> Result<Primitive, Vec<GlassError>> {
+        let path = path.as_ref();
+
+        match fs::read_to_string(path) {
+            Ok(source) => self.eval_source(&path.to_string_lossy(), &source),
+            Err(_) => Err(vec![GlassError::new(ErrorType::UnknownFile(path.display().to_string()), None)]),
+        }
+    }
+
+    // lexes, parses and interprets the source, the result is the value of its last statement. either the
+    // lexing error, every syntax error (up to max_errors) or the runtime error is returned
+    pub fn eval_source(&mut self, filename: &str, source: &str) -> Result<Primitive, Vec<GlassError>> {
+        let tokens = Lexer::new(filename, source).lex().map_err(|error| vec![error])?;
+        let ast = Parser::new(tokens, self.max_errors).parse()?;
+        self.interpreter.interpret(ast.as_ref()).map_err(|error| vec![error])
+    }
+
+    pub fn set_global<T: Into<Primitive>>(&mut self, name: &str, value: T) {
+        self.interpreter.set_global(name, value.into());
+    }
+
+    // lists and dictionaries are shared with the session, so changes made through the returned value
+    // are seen by glass code and the other way around
+    pub fn get_global(&self, name: &str) -> Option<Primitive> {
+        self.interpreter.get_global(name)
+    }
+
+    // makes a rust closure callable from glass as a global function, replacing any global with the same
+    // name. calls with an argument count the arity doesn't accept are rejected before the closure runs
+    pub fn register_function<F>(&mut self, name: &str, arity: Arity, function: F)
+    where
+        F: Fn(Vec<Primitive>) -> NativeResult + 'static,
+    {
+        let function = NativeFunction::new(name, arity, function);
+        self.interpreter.set_global(name, Primitive::NativeFunction(Rc::new(function)));
+    }
+
+    pub fn call_function(&mut self, name: &str, args: Vec<Primitive>) -> Result<Primitive, GlassError> {
+        match self.interpreter.get_global(name) {
+            Some(function) => self.interpreter.call_value(&function, args, None),
+            None => {
+                raise_error!(ErrorType::NoDefiningScope(name.to_string()));
+            }
+        }
+    }
+}
+
+// without this every session that defines a function would never be freed, see clear_globals
+impl Drop for Glass {
+    fn drop(&mut self) {
+        self.interpreter.clear_globals();
+    }
+}
+
+impl Default for Glass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
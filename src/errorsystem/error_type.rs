@@ -1,35 +1,106 @@
 use std::backtrace::Backtrace;
 use std::fmt::Display;
+use std::io;
 
+use strum_macros::IntoStaticStr;
+
+use crate::lang::interpreter::primitive::Primitive;
 use crate::lang::lexer::token::token_type::TokenType;
 
-pub enum ErrorType<'a> {
-    GenericError(&'a str),
+#[derive(IntoStaticStr)]
+pub enum ErrorType {
+    GenericError(&'static str),
     DoubleDecimal,
     DecimalEnding,
-    UnknownFile(&'a str),
+    UnknownFile(String),
     DivisionByZero,
     UnknownChar(char),
     UnclosedString,
     UnknownEscapeSequence(char),
     UnexpectedExpression(TokenType, TokenType),
-    InvalidInversion(&'a str),
+    UnexpectedToken(TokenType),
+    InvalidAssignment,
+    InvalidDeletion,
+    ReturnOutsideFunction,
+    OutsideLoop(&'static str),
+    InvalidInversion(&'static str),
     InvalidOperator(String),
-    NoOperatorDefinition(&'a str),
-    InvalidIndex(usize),
-    NoDefiningScope(&'a str),
-    OutOfBounds(usize, usize),
-    UnexpectedType(&'a str, &'a str),
-    InvalidCall(&'a str),
+    NoOperatorDefinition(&'static str),
+    InvalidIndex(f64),
+    NoDefiningScope(String),
+    OutOfBounds(i64, usize),
+    UnknownKey(String),
+    Unhashable(&'static str),
+    UnexpectedType(&'static str, &'static str),
+    InvalidCall(&'static str),
     UnexpectedArgCount(usize, usize),
-    InvalidIteration(&'a str),
-    UnknownKeyword(&'a str),
-    EmptyFile(&'a str),
+    InvalidIteration(&'static str),
+    InvalidStep,
+    UnknownKeyword(String),
+    EmptyFile(String),
     ReachedEndOfFile,
-    Fatal(String, Option<Backtrace>),
+    Thrown(Primitive),
+    Fatal(String, Option<Box<Backtrace>>), // boxed to keep errors (and every result carrying one) small
+    RecursionLimit(usize),
+    NoMethodDefinition(&'static str, String),
+    RepetitionTooLarge(f64),
+    NoAttribute(&'static str, String),
+    NestingLimit(usize),
+    WriteFailed(io::ErrorKind),
+    Native(String), // raised by functions a host program registers, the message is shown as is
+    UnclosedInterpolation,
+}
+
+impl ErrorType {
+    // codes are part of the public interface (--explain, --error-format=json), so once assigned a code
+    // never changes or gets reused, new variants get the next free one
+    pub fn get_code(&self) -> &'static str {
+        match self {
+            ErrorType::GenericError(..) => "G0001",
+            ErrorType::DoubleDecimal => "G0002",
+            ErrorType::DecimalEnding => "G0003",
+            ErrorType::UnknownFile(..) => "G0004",
+            ErrorType::DivisionByZero => "G0005",
+            ErrorType::UnknownChar(..) => "G0006",
+            ErrorType::UnclosedString => "G0007",
+            ErrorType::UnknownEscapeSequence(..) => "G0008",
+            ErrorType::UnexpectedExpression(..) => "G0009",
+            ErrorType::UnexpectedToken(..) => "G0010",
+            ErrorType::InvalidAssignment => "G0011",
+            ErrorType::InvalidDeletion => "G0012",
+            ErrorType::ReturnOutsideFunction => "G0013",
+            ErrorType::OutsideLoop(..) => "G0014",
+            ErrorType::InvalidInversion(..) => "G0015",
+            ErrorType::InvalidOperator(..) => "G0016",
+            ErrorType::NoOperatorDefinition(..) => "G0017",
+            ErrorType::InvalidIndex(..) => "G0018",
+            ErrorType::NoDefiningScope(..) => "G0019",
+            ErrorType::OutOfBounds(..) => "G0020",
+            ErrorType::UnknownKey(..) => "G0021",
+            ErrorType::Unhashable(..) => "G0022",
+            ErrorType::UnexpectedType(..) => "G0023",
+            ErrorType::InvalidCall(..) => "G0024",
+            ErrorType::UnexpectedArgCount(..) => "G0025",
+            ErrorType::InvalidIteration(..) => "G0026",
+            ErrorType::InvalidStep => "G0027",
+            ErrorType::UnknownKeyword(..) => "G0028",
+            ErrorType::EmptyFile(..) => "G0029",
+            ErrorType::ReachedEndOfFile => "G0030",
+            ErrorType::Thrown(..) => "G0031",
+            ErrorType::Fatal(..) => "G0032",
+            ErrorType::RecursionLimit(..) => "G0033",
+            ErrorType::NoMethodDefinition(..) => "G0034",
+            ErrorType::RepetitionTooLarge(..) => "G0035",
+            ErrorType::NoAttribute(..) => "G0036",
+            ErrorType::NestingLimit(..) => "G0037",
+            ErrorType::WriteFailed(..) => "G0038",
+            ErrorType::Native(..) => "G0039",
+            ErrorType::UnclosedInterpolation => "G0040",
+        }
+    }
 }
 
-impl Display for ErrorType<'_> {
+impl Display for ErrorType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ErrorType::GenericError(a1) => write!(f, "Unknown error occurred during the '{}' process", a1),
@@ -38,22 +109,42 @@ impl Display for ErrorType<'_> {
             ErrorType::DecimalEnding => write!(f, "Number cannot end in a decimal"),
             ErrorType::DivisionByZero => write!(f, "Cannot divide by zero"),
             ErrorType::UnknownChar(a1) => write!(f, "Unknown character '{}' encountered", a1),
-            ErrorType::UnclosedString => write!(f, "Unclosed string starting"),
+            ErrorType::UnclosedString => write!(f, "Reached the end of the file before the string was closed"),
             ErrorType::UnknownEscapeSequence(a1) => write!(f, "Escape sequence '{}' unknown", a1),
-            ErrorType::UnexpectedExpression(a1, a2) => write!(f, "Expected {} but got {}", a1.into(): &str, a2.into(): &str),
+            ErrorType::UnexpectedExpression(a1, a2) => write!(f, "Expected {} but got {}", <&str>::from(a1), <&str>::from(a2)),
+            ErrorType::UnexpectedToken(a1) => write!(f, "Unexpected {}", <&str>::from(a1)),
+            ErrorType::InvalidAssignment => write!(f, "Cannot assign to this expression"),
+            ErrorType::InvalidDeletion => write!(f, "Only indexed items can be deleted"),
+            ErrorType::ReturnOutsideFunction => write!(f, "Cannot return outside of a function"),
+            ErrorType::OutsideLoop(a1) => write!(f, "Cannot use '{}' outside of a loop", a1),
             ErrorType::InvalidInversion(a1) => write!(f, "Type '{}' cannot be inverted", a1),
             ErrorType::InvalidOperator(a1) => write!(f, "Operator '{}' is invalid", a1),
             ErrorType::NoOperatorDefinition(a1) => write!(f, "Cannot use operator '{}' on this type", a1),
             ErrorType::InvalidIndex(a1) => write!(f, "Invalid index '{}'", a1),
             ErrorType::NoDefiningScope(a1) => write!(f, "Variable '{}' undefined", a1),
-            ErrorType::OutOfBounds(a1, a2) => write!(f, "Index {} out of bounds for range {}", a1, a2),
+            ErrorType::OutOfBounds(a1, a2) => write!(f, "Index {} out of bounds for length {}", a1, a2),
+            ErrorType::UnknownKey(a1) => write!(f, "Key '{}' not found", a1),
+            ErrorType::Unhashable(a1) => write!(f, "Type '{}' cannot be used as a dictionary key", a1),
             ErrorType::UnexpectedType(a1, a2) => write!(f, "Expected type '{}' but got '{}' instead", a1, a2),
             ErrorType::InvalidCall(a1) => write!(f, "Type '{}' cannot be called", a1),
             ErrorType::UnexpectedArgCount(a1, a2) => write!(f, "Expected {} args but got {}", a1, a2),
             ErrorType::InvalidIteration(a1) => write!(f, "Type '{}' is not iterable", a1),
+            ErrorType::InvalidStep => write!(f, "Loop step cannot be zero"),
             ErrorType::UnknownKeyword(a1) => write!(f, "Keyword '{}' unknown", a1),
             ErrorType::EmptyFile(a1) => write!(f, "File '{}' is empty", a1),
             ErrorType::ReachedEndOfFile => write!(f, "Unexpectedly reached end of file while parsing"),
+            ErrorType::Thrown(a1) => match (a1.get_field("type"), a1.get_field("message")) {
+                (Some(error_type), Some(message)) => write!(f, "{}: {}", error_type, message),
+                _ => write!(f, "{}", a1),
+            },
+            ErrorType::RecursionLimit(a1) => write!(f, "Function calls nested deeper than the limit of {}", a1),
+            ErrorType::NoMethodDefinition(a1, a2) => write!(f, "Type '{}' has no method '{}'", a1, a2),
+            ErrorType::RepetitionTooLarge(a1) => write!(f, "Repeating {} times is too large to fit in memory", a1),
+            ErrorType::NoAttribute(a1, a2) => write!(f, "Type '{}' has no attribute '{}'", a1, a2),
+            ErrorType::NestingLimit(a1) => write!(f, "Code nested deeper than the limit of {}", a1),
+            ErrorType::WriteFailed(a1) => write!(f, "Couldn't write to the standard output: {}", a1),
+            ErrorType::Native(a1) => write!(f, "{}", a1),
+            ErrorType::UnclosedInterpolation => write!(f, "Reached the end of the file before the '{{' in the string was closed"),
             ErrorType::Fatal(a1, a2) => write!(f, "Glass fatal error: '{}' ->\n\t\tPlease report this crash at https://github.com/sb2bg/RustGlass/issues/new?template=glass-crash.md{}", a1, match a2 {
                 Some(a2) => format!("\n\n\tStack trace ->\n\t{}", a2.to_string().trim_end().replace("\n", "\n\t")),
                 None => String::new(),
@@ -0,0 +1,397 @@
+// the longer explanation printed by `--explain <code>`, every code returned by ErrorType::get_code has one
+pub fn get_explanation(code: &str) -> Option<&'static str> {
+    Some(match code.to_ascii_uppercase().as_str() {
+        "G0001" => r#"An internal step of the interpreter failed in a way that should never happen,
+for example a number the lexer accepted that couldn't be converted into a number.
+
+This is a bug in Glass rather than in your program, please report it at
+https://github.com/sb2bg/RustGlass/issues along with the code that caused it."#,
+        "G0002" => r#"A number literal contains more than one decimal point.
+
+Example:
+
+    price = 1.99.5
+
+Fix: a number can only have a single decimal point.
+
+    price = 1.995"#,
+        "G0003" => r#"A number literal ends with a decimal point without any digits after it.
+
+Example:
+
+    half = 5.
+
+Fix: add the fractional part, or drop the decimal point for a whole number.
+
+    half = 5.0"#,
+        "G0004" => r#"The file passed to rust_glass doesn't exist or couldn't be read.
+
+Example:
+
+    rust_glass missing.glass
+
+Fix: check the path and that the file is readable."#,
+        "G0005" => r#"A number was divided by zero, either with `/` or with `%`.
+
+Example:
+
+    total = 10
+    count = 0
+    average = total / count
+
+Fix: check the divisor before dividing.
+
+    average = if count != 0 => total / count else => 0"#,
+        "G0006" => r#"The source contains a character which isn't part of any Glass syntax.
+
+Example:
+
+    cost = $5
+
+Fix: remove the character, identifiers can only contain letters, digits and underscores.
+
+    cost = 5"#,
+        "G0007" => r#"A string was opened with `"` but the file ended before the closing `"`.
+
+Example:
+
+    greeting = "hello
+
+Fix: close the string. Strings can span multiple lines, so the error is reported at the end of the
+file and the label points at where the string started.
+
+    greeting = "hello""#,
+        "G0008" => r#"A string contains a backslash followed by a character that isn't a known escape
+sequence. The escape sequences are \a, \b, \f, \n, \t, \r, \', \" and \\.
+
+Example:
+
+    path = "C:\glass"
+
+Fix: escape the backslash itself.
+
+    path = "C:\\glass""#,
+        "G0009" => r#"The parser expected one kind of token but found another, e.g. a missing `=>`,
+closing bracket or `end`.
+
+Example:
+
+    func add(a, b) a + b
+
+Fix: add the token the error asks for.
+
+    func add(a, b) => a + b"#,
+        "G0010" => r#"A token appeared where no expression can start.
+
+Example:
+
+    x = * 2
+
+Fix: complete the expression.
+
+    x = 3 * 2"#,
+        "G0011" => r#"The left hand side of an assignment isn't something which can be assigned to.
+Only variables and indexed items (`list[0]`, `dict["key"]`) can be assigned.
+
+Example:
+
+    5 = x
+
+Fix: put the target on the left.
+
+    x = 5"#,
+        "G0012" => r#"Only items of a list or a dictionary can be deleted.
+
+Example:
+
+    x = 5
+    delete x
+
+Fix: delete an indexed item, variables can be set to void instead.
+
+    items = [1, 2, 3]
+    delete items[0]"#,
+        "G0013" => r#"`return` was used outside of a function body.
+
+Example:
+
+    return 5
+
+Fix: only return from inside a function.
+
+    func five() => return 5"#,
+        "G0014" => r#"`break` or `continue` was used outside of a loop. Loops outside of a function
+can't be broken out of from inside of it either.
+
+Example:
+
+    break
+
+Fix: only use them inside of a loop body.
+
+    while true => break"#,
+        "G0015" => r#"Unary `-` or `+` was applied to a value that isn't a number.
+
+Example:
+
+    x = -"five"
+
+Fix: only negate numbers.
+
+    x = -5"#,
+        "G0016" => r#"A sequence of operator characters doesn't form a known operator.
+
+Example:
+
+    same = 5 <> 3
+
+Fix: use one of the operators Glass supports.
+
+    same = 5 != 3"#,
+        "G0017" => r#"An operator was used on values it isn't defined for, e.g. subtracting strings.
+The note says which types the operands had.
+
+Example:
+
+    x = "ten" - "one"
+
+Fix: convert the values, or use an operator which is defined for them.
+
+    x = 10 - 1"#,
+        "G0018" => r#"A list was indexed with a number that isn't a whole number.
+
+Example:
+
+    items = [1, 2, 3]
+    x = items[1.5]
+
+Fix: index with whole numbers.
+
+    x = items[1]"#,
+        "G0019" => r#"A variable was used before anything was assigned to it. Functions can only see
+variables from the scope they were defined in, not from the scope they're called from.
+
+Example:
+
+    total = 1
+    x = totl + 1
+
+Fix: check the spelling (the help message suggests close names in scope) or assign it first.
+
+    x = total + 1"#,
+        "G0020" => r#"A list was indexed past its end. Indexes start at 0 and negative indexes count
+from the end, so valid indexes of a list of length n go from -n to n - 1.
+
+Example:
+
+    items = [1, 2, 3]
+    x = items[3]
+
+Fix: stay inside of the list.
+
+    x = items[2]"#,
+        "G0021" => r#"A dictionary was indexed with a key it doesn't contain.
+
+Example:
+
+    ages = {"ada": 36}
+    x = ages["alan"]
+
+Fix: check whether the key exists with `in` first.
+
+    x = if "alan" in ages => ages["alan"] else => 0"#,
+        "G0022" => r#"A value that can't be hashed was used as a dictionary key. Lists, dictionaries
+and functions can't be keys since they can change (or have no meaningful equality), and neither can
+NaN since it isn't equal to itself.
+
+Example:
+
+    points = {[0, 0]: "origin"}
+
+Fix: use a string or number key.
+
+    points = {"0, 0": "origin"}"#,
+        "G0023" => r#"A value of one type was used where another type is required, e.g. a loop bound
+that isn't a number or a condition that has no truth value.
+
+Example:
+
+    for i = 0 to "10" => i
+
+Fix: pass a value of the expected type.
+
+    for i = 0 to 10 => i"#,
+        "G0024" => r#"A value that isn't a function was called.
+
+Example:
+
+    x = 5
+    y = x(1)
+
+Fix: only call functions.
+
+    func x(n) => n * 5
+    y = x(1)"#,
+        "G0025" => r#"A function was called with a different number of arguments than it has
+parameters. The note shows how the function is defined.
+
+Example:
+
+    func add(a, b) => a + b
+    x = add(1)
+
+Fix: pass an argument for every parameter.
+
+    x = add(1, 2)"#,
+        "G0026" => r#"A for loop tried to iterate over a value which isn't iterable. Lists, strings
+(their characters) and dictionaries (their keys) can be iterated.
+
+Example:
+
+    for x in 5 => x
+
+Fix: iterate over a list, or use a numeric loop.
+
+    for x = 0 to 5 => x"#,
+        "G0027" => r#"A numeric for loop has a step of zero, which would never reach its end.
+
+Example:
+
+    for i = 0 to 10 step 0 => i
+
+Fix: use a non zero step, negative steps count down.
+
+    for i = 0 to 10 step 2 => i"#,
+        "G0028" => r#"A statement starts with a word that isn't a keyword but is followed by more
+code, which is almost always a misspelled keyword.
+
+Example:
+
+    fucn add(a, b) => a + b
+
+Fix: correct the keyword, the help message suggests the closest one.
+
+    func add(a, b) => a + b"#,
+        "G0029" => r#"The file passed to rust_glass is empty.
+
+Example:
+
+    rust_glass empty.glass
+
+Fix: add some code to the file."#,
+        "G0030" => r#"The file ended in the middle of a statement, usually a block missing its `end`.
+
+Example:
+
+    func double(x) =>
+        return x * 2
+
+Fix: close the block.
+
+    func double(x) =>
+        return x * 2
+    end"#,
+        "G0031" => r#"An error raised with `throw` wasn't caught by any `try` block.
+
+Example:
+
+    throw "something went wrong"
+
+Fix: catch the error where it can be handled.
+
+    try =>
+        throw "something went wrong"
+    catch err =>
+        message = err.message
+    end"#,
+        "G0032" => r#"The interpreter itself crashed. This is always a bug in Glass rather than in
+your program, please report it at https://github.com/sb2bg/RustGlass/issues/new?template=glass-crash.md
+along with the code that caused it and the printed stack trace."#,
+        "G0033" => r#"Function calls were nested deeper than the maximum call depth, which is 1000
+unless it's changed with --max-depth. This is almost always recursion without a (reachable) base case.
+
+Example:
+
+    func countdown(n) => countdown(n - 1)
+    countdown(10)
+
+Fix: stop recursing once the work is done.
+
+    func countdown(n) => if n > 0 => countdown(n - 1)
+    countdown(10)
+
+Legitimately deep recursion can raise the limit instead: rust_glass --max-depth 100000 file.glass"#,
+        "G0034" => r#"A method was called on a value whose type doesn't have a method by that name.
+Method names are case sensitive.
+
+Example:
+
+    name = "glass"
+    loud = name.Upper()
+
+Fix: call a method the type has.
+
+    loud = name.upper()"#,
+        "G0035" => r#"A list or string was repeated with `*` more times than there is memory for the result.
+
+Example:
+
+    grid = [0] * 100000000000000
+
+Fix: build only as much as is needed, or check the count before repeating.
+
+    grid = [0] * 1000"#,
+        "G0036" => r#"An attribute was read from a value that isn't a dictionary. Only dictionaries
+have attributes, `value.name` reads the key "name" the same as `value["name"]`.
+
+Example:
+
+    name = "glass"
+    loud = name.upper
+
+Fix: call the method if that's what was meant.
+
+    loud = name.upper()"#,
+        "G0037" => r#"Expressions or statements were nested more than 200 levels deep, for example
+200 pairs of parentheses around a value or 200 `if` blocks inside of each other. Code this deeply nested
+is almost always generated, and is rejected before it can exhaust the parser's stack.
+
+Fix: split the nested parts into variables or functions.
+
+    inner = (a + b) * c
+    result = (inner - d) / e"#,
+        "G0038" => r#"`print` or `println` couldn't write to the standard output, usually because it was
+closed. When the output is piped into a program that stops reading early, such as `head`, rust_glass
+stops quietly instead of reporting this error.
+
+Example:
+
+    rust_glass numbers.glass > /dev/full
+
+Fix: make sure the output goes somewhere that can be written to."#,
+        "G0039" => r#"A function provided by the program Glass is embedded in failed. The message comes
+from that program and describes what went wrong, Glass itself didn't run into a problem.
+
+Like any other error it can be caught:
+
+    try =>
+        data = load("config")
+    catch err =>
+        println("couldn't load the config:", err.message)
+    end
+
+Fix: see the message, or the documentation of the program providing the function."#,
+        "G0040" => r#"A `{` in a string starts an interpolation, an expression whose value is inserted into
+the string, which has to be closed with `}`. Without one the rest of the file, including the quote
+meant to close the string, is read as the expression.
+
+Example:
+
+    println("{")
+
+Fix: close the interpolation, or escape the brace with a backslash to put it in the string as is.
+
+    println("\{")"#,
+        _ => return None,
+    })
+}
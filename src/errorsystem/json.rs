@@ -0,0 +1,61 @@
+use crate::errorsystem::GlassError;
+use crate::lang::lexer::position::Position;
+
+// a single line json object per error, written by hand to avoid pulling in a serialization crate for
+// one flat structure
+pub fn to_json(error: &GlassError) -> String {
+    let inner = &error.inner;
+    let labels: Vec<String> = inner.labels.iter()
+        .map(|(pos, label)| format!("{{\"message\":{},{}}}", string(label), span(Some(pos))))
+        .collect();
+    let notes: Vec<String> = inner.notes.iter().map(|note| string(note)).collect();
+    let traceback: Vec<String> = inner.traceback.iter().flatten()
+        .map(|frame| format!("{{\"function\":{},{}}}", string(frame.get_name()), span(frame.get_call_site())))
+        .collect();
+
+    format!(
+        "{{\"code\":{},\"severity\":\"error\",\"message\":{},{},\"labels\":[{}],\"help\":{},\"notes\":[{}],\"traceback\":[{}]}}",
+        string(inner.error_type.get_code()),
+        string(&inner.error_type.to_string()),
+        span(inner.position.as_ref()),
+        labels.join(","),
+        inner.help.as_deref().map_or(String::from("null"), string),
+        notes.join(","),
+        traceback.join(","),
+    )
+}
+
+// lines and columns start at 1, the end column is exclusive
+fn span(pos: Option<&Position>) -> String {
+    match pos {
+        Some(pos) => {
+            let (line_end, column_end) = pos.get_end();
+
+            format!(
+                "\"filename\":{},\"line_start\":{},\"column_start\":{},\"line_end\":{},\"column_end\":{}",
+                string(pos.get_filename()), pos.get_row(), pos.get_column(), line_end, column_end,
+            )
+        }
+        None => String::from("\"filename\":null,\"line_start\":null,\"column_start\":null,\"line_end\":null,\"column_end\":null"),
+    }
+}
+
+fn string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
@@ -1,37 +1,232 @@
-use crate::ErrorType;
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::errorsystem::error_type::ErrorType;
 use crate::lang::lexer::position::Position;
 
 pub mod error_type;
+pub mod explain;
+mod json;
+pub mod suggestion;
 
-#[macro_export]
-macro_rules! dispatch_error {
+// returns the error from the enclosing function, converting it into whatever error type it returns
+macro_rules! raise_error {
     ($error: expr, $pos: expr) => {
-        eprintln!("\n\tFatal exception during runtime -> \"{} at {}\"", $error, $pos);
-        std::process::exit(1); // This is to make the compiler happy (avoid mismatched arms)
+        return Err($crate::errorsystem::GlassError::new($error, Some($pos)).into())
     };
 
     ($error: expr) => {
-        eprintln!("\n\tFatal exception during runtime -> \"{}\"", $error);
-        std::process::exit(1); // This is to make the compiler happy (avoid mismatched arms)
+        return Err($crate::errorsystem::GlassError::new($error, None).into())
     };
 }
 
-pub struct ErrorHandler {
-    in_catch_block: bool,
+pub(crate) use raise_error;
+
+static COLOR: AtomicBool = AtomicBool::new(false);
+static JSON: AtomicBool = AtomicBool::new(false);
+
+const RED: &str = "\x1b[1;31m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+// whether errors are printed with ANSI colors, set once by the binary from --color
+pub fn set_color(enabled: bool) {
+    COLOR.store(enabled, Ordering::Relaxed);
+}
+
+// whether errors are reported as json (one object per line) instead of text, set by the binary from
+// --error-format
+pub fn set_json(enabled: bool) {
+    JSON.store(enabled, Ordering::Relaxed);
+}
+
+fn paint(text: &str, color: &str) -> String {
+    if COLOR.load(Ordering::Relaxed) {
+        format!("{}{}{}", color, text, RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+// a glass function call which was in progress when an error occurred, functions called by the host
+// program through the engine have no call site
+#[derive(Clone)]
+pub struct Frame {
+    name: String,
+    call_site: Option<Position>,
+}
+
+impl Frame {
+    pub fn new(name: String, call_site: Option<Position>) -> Self {
+        Frame { name, call_site }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_call_site(&self) -> Option<&Position> {
+        self.call_site.as_ref()
+    }
+}
+
+// boxed since errors are returned all over the place and most results don't hold one. like the values
+// and positions it holds an error isn't Send or Sync, a thrown value is caught as the same (shared) value
+// so it can't be copied into something that is. to pass one to another thread, send its to_string()
+pub struct GlassError {
+    inner: Box<ErrorInner>,
+}
+
+struct ErrorInner {
+    error_type: ErrorType,
+    position: Option<Position>,
+    labels: Vec<(Position, String)>, // secondary positions related to the error, e.g. where a string started
+    help: Option<String>,
+    notes: Vec<String>,
+    traceback: Option<Vec<Frame>>, // outermost call first, None until the error leaves the call it occurred in
+}
+
+impl GlassError {
+    pub fn new(error_type: ErrorType, position: Option<Position>) -> Self {
+        GlassError { inner: Box::new(ErrorInner { error_type, position, labels: Vec::new(), help: None, notes: Vec::new(), traceback: None }) }
+    }
+
+    pub fn with_label(mut self, position: Position, label: &str) -> Self {
+        self.inner.labels.push((position, label.to_string()));
+        self
+    }
+
+    pub fn with_help(mut self, help: String) -> Self {
+        self.inner.help = Some(help);
+        self
+    }
+
+    pub fn with_note(mut self, note: String) -> Self {
+        self.inner.notes.push(note);
+        self
+    }
+
+    // used for errors raised somewhere that doesn't know where it was called from, such as a native function
+    pub fn with_position_if_missing(mut self, position: Option<Position>) -> Self {
+        if self.inner.position.is_none() {
+            self.inner.position = position;
+        }
+
+        self
+    }
+
+    // the call stack at the point the error occurred, so only the first (innermost) one attached is kept
+    pub fn attach_traceback(&mut self, frames: &[Frame]) {
+        if self.inner.traceback.is_none() {
+            self.inner.traceback = Some(frames.to_vec());
+        }
+    }
+
+    pub fn get_traceback(&self) -> Option<&[Frame]> {
+        self.inner.traceback.as_deref()
+    }
+
+    pub fn get_error_type(&self) -> &ErrorType {
+        &self.inner.error_type
+    }
+
+    pub fn get_position(&self) -> Option<&Position> {
+        self.inner.position.as_ref()
+    }
+}
+
+// the line the position is on with the spanned range underlined, spans running past the end of the
+// line are cut off there
+fn write_snippet(f: &mut std::fmt::Formatter<'_>, pos: &Position, marker: char, color: &str, label: Option<&str>) -> std::fmt::Result {
+    if let Some(line) = pos.get_line() {
+        let trimmed = line.trim_start();
+        let offset = line.chars().count() - trimmed.chars().count();
+        let col = (pos.get_column() - 1).saturating_sub(offset);
+        let length = pos.get_length().min(trimmed.chars().count().saturating_sub(col)).max(1);
+        let underline = marker.to_string().repeat(length);
+
+        write!(f, "\n\t\t{}\n\t\t{}{}", trimmed, " ".repeat(col), paint(&underline, color))?;
+
+        if let Some(label) = label {
+            write!(f, " {}", paint(label, color))?;
+        }
+    }
+
+    write!(f, "\n\t[{}]", pos)
 }
 
-impl ErrorHandler {
-    pub fn new() -> Self {
-        Self { in_catch_block: false }
+// runs of calls to the same function (recursion) are collapsed to their first two and last frame
+fn write_traceback(f: &mut std::fmt::Formatter<'_>, frames: &[Frame]) -> std::fmt::Result {
+    write!(f, "\n\t{}", paint("traceback (most recent call last):", BOLD))?;
+
+    let mut start = 0;
+
+    while start < frames.len() {
+        let name = frames[start].get_name();
+        let run = frames[start..].iter().take_while(|frame| frame.get_name() == name).count();
+
+        for (i, frame) in frames[start..start + run].iter().enumerate() {
+            if run > 4 && i == 2 {
+                write!(f, "\n\t\t... {} more frames of {}", run - 3, name)?;
+            } else if run <= 4 || i < 2 || i == run - 1 {
+                match frame.get_call_site() {
+                    Some(pos) => write!(f, "\n\t\t{} calling '{}'", pos, frame.get_name())?,
+                    None => write!(f, "\n\t\t<host> calling '{}'", frame.get_name())?,
+                }
+            }
+        }
+
+        start += run;
     }
 
-    // if the error occurs in a catch block, we don't want to print the error message, but rather
-    // give the error to the catch block and let it handle it
-    pub fn handle_runtime_error<'a>(&mut self, error: ErrorType<'a>, pos: Position) -> ErrorType<'a> {
-        if self.in_catch_block {
-            error
-        } else {
-            dispatch_error!(error, pos);
+    Ok(())
+}
+
+impl Display for GlassError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let inner = &self.inner;
+        let heading = format!("error[{}]", inner.error_type.get_code());
+        write!(f, "\n\t{}: {}", paint(&heading, RED), paint(&inner.error_type.to_string(), BOLD))?;
+
+        if let Some(pos) = &inner.position {
+            write_snippet(f, pos, '^', RED, None)?;
+        }
+
+        for (pos, label) in &inner.labels {
+            write_snippet(f, pos, '-', BLUE, Some(label))?;
+        }
+
+        if let Some(frames) = inner.traceback.as_deref().filter(|frames| !frames.is_empty()) {
+            write_traceback(f, frames)?;
+        }
+
+        if let Some(help) = &inner.help {
+            write!(f, "\n\t{}: {}", paint("help", BOLD), help)?;
+        }
+
+        for note in &inner.notes {
+            write!(f, "\n\t{}: {}", paint("note", BOLD), note)?;
         }
+
+        Ok(())
     }
-}
\ No newline at end of file
+}
+
+// debug shows the same report as display, so `unwrap` and `?` in a host program print something useful
+impl Debug for GlassError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Error for GlassError {}
+
+pub fn report(error: &GlassError) {
+    if JSON.load(Ordering::Relaxed) {
+        eprintln!("{}", json::to_json(error));
+    } else {
+        eprintln!("{}", error);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::errorsystem::error_type::ErrorType;
+use crate::errorsystem::raise_error;
+use crate::lang::interpreter::methods::{as_dict, Method, MethodCall, MethodResult};
+use crate::lang::interpreter::primitive::native_function::Arity;
+use crate::lang::interpreter::primitive::Primitive;
+
+// keys, values and items return new lists in the same order, the order the keys were first inserted in
+pub const METHODS: &[Method] = &[
+    Method { name: "len", arity: Arity::Exact(0), function: len },
+    Method { name: "keys", arity: Arity::Exact(0), function: keys },
+    Method { name: "values", arity: Arity::Exact(0), function: values },
+    Method { name: "items", arity: Arity::Exact(0), function: items },
+    Method { name: "get", arity: Arity::Between(1, 2), function: get },
+];
+
+fn len(call: &mut MethodCall) -> MethodResult {
+    Ok(Primitive::Number(as_dict(call.get_target())?.borrow().len() as f64))
+}
+
+fn keys(call: &mut MethodCall) -> MethodResult {
+    Ok(Primitive::new_list(as_dict(call.get_target())?.borrow().keys().cloned().collect()))
+}
+
+fn values(call: &mut MethodCall) -> MethodResult {
+    Ok(Primitive::new_list(as_dict(call.get_target())?.borrow().values().cloned().collect()))
+}
+
+// each entry as a `[key, value]` list
+fn items(call: &mut MethodCall) -> MethodResult {
+    let entries = as_dict(call.get_target())?.borrow();
+    Ok(Primitive::new_list(entries.iter().map(|(key, value)| Primitive::new_list(vec![key.clone(), value.clone()])).collect()))
+}
+
+// the value of the key, or the default (void if there's none) instead of an error when it's missing
+fn get(call: &mut MethodCall) -> MethodResult {
+    let key = call.get_arg(0);
+
+    if !key.is_hashable() {
+        raise_error!(ErrorType::Unhashable(key.get_type().get_name()));
+    }
+
+    Ok(match as_dict(call.get_target())?.borrow().get(key) {
+        Some(value) => value.clone(),
+        None => call.get_args().get(1).cloned().unwrap_or(Primitive::Void),
+    })
+}
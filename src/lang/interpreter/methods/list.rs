@@ -0,0 +1,128 @@
+use std::cmp::Ordering;
+
+use crate::errorsystem::error_type::ErrorType;
+use crate::errorsystem::raise_error;
+use crate::errorsystem::GlassError;
+use crate::lang::interpreter::methods::{as_list, Method, MethodCall, MethodResult};
+use crate::lang::interpreter::primitive::native_function::Arity;
+use crate::lang::interpreter::primitive::{Primitive, Type};
+use crate::lang::lexer::token::token_type::TokenType;
+
+// push, pop, insert, sort and reverse change the list in place, map and filter return a new one
+pub const METHODS: &[Method] = &[
+    Method { name: "len", arity: Arity::Exact(0), function: len },
+    Method { name: "push", arity: Arity::Exact(1), function: push },
+    Method { name: "pop", arity: Arity::Between(0, 1), function: pop },
+    Method { name: "insert", arity: Arity::Exact(2), function: insert },
+    Method { name: "sort", arity: Arity::Between(0, 1), function: sort },
+    Method { name: "reverse", arity: Arity::Exact(0), function: reverse },
+    Method { name: "map", arity: Arity::Exact(1), function: map },
+    Method { name: "filter", arity: Arity::Exact(1), function: filter },
+];
+
+fn len(call: &mut MethodCall) -> MethodResult {
+    Ok(Primitive::Number(as_list(call.get_target())?.borrow().len() as f64))
+}
+
+fn push(call: &mut MethodCall) -> MethodResult {
+    as_list(call.get_target())?.borrow_mut().push(call.get_arg(0).clone());
+    Ok(Primitive::Void)
+}
+
+// removes and returns the item at the index, the last one by default
+fn pop(call: &mut MethodCall) -> MethodResult {
+    let mut items = as_list(call.get_target())?.borrow_mut();
+    let index = call.get_args().first().unwrap_or(&Primitive::Number(-1.0)).to_index(items.len())?;
+    Ok(items.remove(index))
+}
+
+// inserts before the item at the index, an index of the list's length appends
+fn insert(call: &mut MethodCall) -> MethodResult {
+    let mut items = as_list(call.get_target())?.borrow_mut();
+    let len = items.len();
+
+    let index = match call.get_arg(0) {
+        Primitive::Number(index) if *index == len as f64 => len,
+        index => index.to_index(len)?,
+    };
+
+    items.insert(index, call.get_arg(1).clone());
+    Ok(Primitive::Void)
+}
+
+fn reverse(call: &mut MethodCall) -> MethodResult {
+    as_list(call.get_target())?.borrow_mut().reverse();
+    Ok(Primitive::Void)
+}
+
+// the functions are called on a copy of the items, so they can change the list without affecting the loop
+fn map(call: &mut MethodCall) -> MethodResult {
+    let items = as_list(call.get_target())?.borrow().clone();
+    let function = call.get_arg(0).clone();
+    let mut mapped = Vec::with_capacity(items.len());
+
+    for item in items {
+        mapped.push(call.call(&function, vec![item])?);
+    }
+
+    Ok(Primitive::new_list(mapped))
+}
+
+fn filter(call: &mut MethodCall) -> MethodResult {
+    let items = as_list(call.get_target())?.borrow().clone();
+    let function = call.get_arg(0).clone();
+    let mut kept = Vec::new();
+
+    for item in items {
+        let keep = call.call(&function, vec![item.clone()])?;
+
+        match keep.is_truthy() {
+            Some(true) => kept.push(item),
+            Some(false) => {}
+            None => {
+                raise_error!(ErrorType::UnexpectedType(Type::Bool.get_name(), keep.get_type().get_name()));
+            }
+        }
+    }
+
+    Ok(Primitive::new_list(kept))
+}
+
+// a stable sort, by the value the key function returns for each item if one is given. numbers, strings
+// and bools can be sorted, but only among values of the same type
+fn sort(call: &mut MethodCall) -> MethodResult {
+    let list = as_list(call.get_target())?.clone();
+    let items = list.borrow().clone();
+
+    let keys = match call.get_args().first().cloned() {
+        Some(key) => items.iter().map(|item| call.call(&key, vec![item.clone()])).collect::<Result<Vec<Primitive>, GlassError>>()?,
+        None => items.clone(),
+    };
+
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    let mut error = None;
+
+    order.sort_by(|&a, &b| compare(&keys[a], &keys[b]).unwrap_or_else(|cause| {
+        error.get_or_insert(cause);
+        Ordering::Equal
+    }));
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    *list.borrow_mut() = order.into_iter().map(|index| items[index].clone()).collect();
+    Ok(Primitive::Void)
+}
+
+fn compare(left: &Primitive, right: &Primitive) -> Result<Ordering, GlassError> {
+    Ok(match (left, right) {
+        (Primitive::Number(left), Primitive::Number(right)) => left.partial_cmp(right).unwrap_or(Ordering::Equal),
+        (Primitive::String(left), Primitive::String(right)) => left.cmp(right),
+        (Primitive::Bool(left), Primitive::Bool(right)) => left.cmp(right),
+        (left, right) => {
+            let error = GlassError::new(ErrorType::NoOperatorDefinition(TokenType::LessThan.get_symbol()), None);
+            return Err(error.with_note(format!("sort compared a '{}' with a '{}'", left.get_type().get_name(), right.get_type().get_name())));
+        }
+    })
+}
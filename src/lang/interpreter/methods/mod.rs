@@ -0,0 +1,114 @@
+use std::rc::Rc;
+
+use crate::errorsystem::error_type::ErrorType;
+use crate::errorsystem::raise_error;
+use crate::errorsystem::{GlassError, suggestion};
+use crate::lang::interpreter::Interpreter;
+use crate::lang::interpreter::primitive::native_function::Arity;
+use crate::lang::interpreter::primitive::shared::{Dictionary, List};
+use crate::lang::interpreter::primitive::{Primitive, Type};
+use crate::lang::lexer::position::Position;
+
+mod dict;
+mod list;
+mod string;
+
+pub type MethodResult = Result<Primitive, GlassError>;
+
+// errors returned by a method without a position are reported at the method name
+pub struct Method {
+    name: &'static str,
+    arity: Arity,
+    function: fn(&mut MethodCall) -> MethodResult,
+}
+
+// a single call of a method, the target is always of the type the method belongs to and the argument
+// count has already been checked against its arity
+pub struct MethodCall<'a> {
+    interpreter: &'a mut Interpreter,
+    target: Primitive,
+    args: Vec<Primitive>,
+    call_site: Position,
+}
+
+impl MethodCall<'_> {
+    pub fn get_target(&self) -> &Primitive {
+        &self.target
+    }
+
+    pub fn get_arg(&self, index: usize) -> &Primitive {
+        &self.args[index]
+    }
+
+    pub fn get_args(&self) -> &[Primitive] {
+        &self.args
+    }
+
+    // calls a function passed to the method, e.g. the one given to map
+    pub fn call(&mut self, function: &Primitive, args: Vec<Primitive>) -> MethodResult {
+        self.interpreter.call_value(function, args, Some(self.call_site.clone()))
+    }
+}
+
+// the method table of each type, types which aren't listed have no methods
+fn get_methods(value_type: Type) -> &'static [Method] {
+    match value_type {
+        Type::String => string::METHODS,
+        Type::List => list::METHODS,
+        Type::Dictionary => dict::METHODS,
+        _ => &[],
+    }
+}
+
+pub fn has_method(value_type: Type, name: &str) -> bool {
+    get_methods(value_type).iter().any(|method| method.name == name)
+}
+
+pub fn call_method(interpreter: &mut Interpreter, target: Primitive, name: &str, args: Vec<Primitive>, call_site: Position) -> MethodResult {
+    let value_type = target.get_type();
+    let methods = get_methods(value_type);
+
+    let method = match methods.iter().find(|method| method.name == name) {
+        Some(method) => method,
+        None => {
+            let error = GlassError::new(ErrorType::NoMethodDefinition(value_type.get_name(), name.to_string()), None);
+
+            return Err(match suggestion::did_you_mean(name, methods.iter().map(|method| method.name)) {
+                Some(method) => error.with_help(format!("did you mean '{}'?", method)),
+                None => error,
+            });
+        }
+    };
+
+    method.arity.check(name, args.len())?;
+    (method.function)(&mut MethodCall { interpreter, target, args, call_site })
+}
+
+// the arguments and targets of methods, which raise an error when the value has a different type
+
+fn as_string(value: &Primitive) -> Result<&str, GlassError> {
+    match value {
+        Primitive::String(value) => Ok(value),
+        value => {
+            raise_error!(ErrorType::UnexpectedType(Type::String.get_name(), value.get_type().get_name()));
+        }
+    }
+}
+
+fn as_list(value: &Primitive) -> Result<&Rc<List>, GlassError> {
+    match value {
+        Primitive::List(items) => Ok(items),
+        value => {
+            raise_error!(ErrorType::UnexpectedType(Type::List.get_name(), value.get_type().get_name()));
+        }
+    }
+}
+
+fn as_dict(value: &Primitive) -> Result<&Rc<Dictionary>, GlassError> {
+    match value {
+        Primitive::Dictionary(entries) => Ok(entries),
+        value => {
+            raise_error!(ErrorType::UnexpectedType(Type::Dictionary.get_name(), value.get_type().get_name()));
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::lang::interpreter::methods::{as_list, as_string, Method, MethodCall, MethodResult};
+use crate::lang::interpreter::primitive::native_function::Arity;
+use crate::lang::interpreter::primitive::Primitive;
+
+// strings are immutable, so every method returns a new value. lengths and positions count characters
+pub const METHODS: &[Method] = &[
+    Method { name: "len", arity: Arity::Exact(0), function: len },
+    Method { name: "upper", arity: Arity::Exact(0), function: upper },
+    Method { name: "lower", arity: Arity::Exact(0), function: lower },
+    Method { name: "trim", arity: Arity::Exact(0), function: trim },
+    Method { name: "split", arity: Arity::Between(0, 1), function: split },
+    Method { name: "join", arity: Arity::Exact(1), function: join },
+    Method { name: "replace", arity: Arity::Exact(2), function: replace },
+    Method { name: "find", arity: Arity::Exact(1), function: find },
+    Method { name: "starts_with", arity: Arity::Exact(1), function: starts_with },
+];
+
+fn len(call: &mut MethodCall) -> MethodResult {
+    Ok(Primitive::Number(as_string(call.get_target())?.chars().count() as f64))
+}
+
+fn upper(call: &mut MethodCall) -> MethodResult {
+    Ok(Primitive::String(as_string(call.get_target())?.to_uppercase()))
+}
+
+fn lower(call: &mut MethodCall) -> MethodResult {
+    Ok(Primitive::String(as_string(call.get_target())?.to_lowercase()))
+}
+
+fn trim(call: &mut MethodCall) -> MethodResult {
+    Ok(Primitive::String(as_string(call.get_target())?.trim().to_string()))
+}
+
+// without a separator the string is split on whitespace, with an empty one into characters
+fn split(call: &mut MethodCall) -> MethodResult {
+    let value = as_string(call.get_target())?;
+
+    let parts: Vec<Primitive> = match call.get_args().first() {
+        None => value.split_whitespace().map(|part| Primitive::String(part.to_string())).collect(),
+        Some(separator) => match as_string(separator)? {
+            "" => value.chars().map(|c| Primitive::String(c.to_string())).collect(),
+            separator => value.split(separator).map(|part| Primitive::String(part.to_string())).collect(),
+        },
+    };
+
+    Ok(Primitive::new_list(parts))
+}
+
+// `", ".join(items)`, items which aren't strings are joined as they would be printed
+fn join(call: &mut MethodCall) -> MethodResult {
+    let items: Vec<String> = as_list(call.get_arg(0))?.borrow().iter().map(|item| item.to_string()).collect();
+    Ok(Primitive::String(items.join(as_string(call.get_target())?)))
+}
+
+fn replace(call: &mut MethodCall) -> MethodResult {
+    let value = as_string(call.get_target())?;
+    Ok(Primitive::String(value.replace(as_string(call.get_arg(0))?, as_string(call.get_arg(1))?)))
+}
+
+// -1 if the substring isn't found
+fn find(call: &mut MethodCall) -> MethodResult {
+    let value = as_string(call.get_target())?;
+
+    Ok(Primitive::Number(match value.find(as_string(call.get_arg(0))?) {
+        Some(index) => value[..index].chars().count() as f64,
+        None => -1.0,
+    }))
+}
+
+fn starts_with(call: &mut MethodCall) -> MethodResult {
+    Ok(Primitive::Bool(as_string(call.get_target())?.starts_with(as_string(call.get_arg(0))?)))
+}
@@ -1,35 +1,875 @@
-/* for future reference
-match (x, y) {
-    (Int(i), Int(j)) => {…},
-    (Str(s), Str(d)) => {…},
-    _ => panic!("Type error!")
-}
- */
-
 use std::cell::RefCell;
+use std::mem;
 use std::rc::Rc;
 
+use indexmap::IndexMap;
+
+use crate::errorsystem::error_type::ErrorType;
+use crate::errorsystem::raise_error;
+use crate::errorsystem::{Frame, GlassError, suggestion};
+use crate::lang::interpreter::primitive::function::Function;
+use crate::lang::interpreter::primitive::native_function::NativeFunction;
+use crate::lang::interpreter::primitive::{Primitive, Type};
 use crate::lang::interpreter::scope::Scope;
+use crate::lang::lexer::position::Position;
+use crate::lang::lexer::token::Token;
+use crate::lang::lexer::token::token_type::TokenType;
+use crate::lang::parser::node::attribute_node::AttributeNode;
+use crate::lang::parser::node::bin_op_node::BinOpNode;
+use crate::lang::parser::node::block_node::BlockNode;
+use crate::lang::parser::node::bool_node::BoolNode;
+use crate::lang::parser::node::break_node::BreakNode;
+use crate::lang::parser::node::call_node::CallNode;
+use crate::lang::parser::node::continue_node::ContinueNode;
+use crate::lang::parser::node::delete_node::DeleteNode;
+use crate::lang::parser::node::dict_node::DictNode;
+use crate::lang::parser::node::for_in_node::ForInNode;
+use crate::lang::parser::node::for_node::ForNode;
+use crate::lang::parser::node::func_def_node::FuncDefNode;
+use crate::lang::parser::node::function_node::FunctionNode;
+use crate::lang::parser::node::if_node::IfNode;
+use crate::lang::parser::node::index_assign_node::IndexAssignNode;
+use crate::lang::parser::node::index_node::IndexNode;
+use crate::lang::parser::node::interpolation_node::InterpolationNode;
+use crate::lang::parser::node::list_node::ListNode;
+use crate::lang::parser::node::method_call_node::MethodCallNode;
+use crate::lang::parser::node::Node;
+use crate::lang::parser::node::number_node::NumberNode;
+use crate::lang::parser::node::return_node::ReturnNode;
+use crate::lang::parser::node::slice_node::SliceNode;
+use crate::lang::parser::node::string_node::StringNode;
+use crate::lang::parser::node::throw_node::ThrowNode;
+use crate::lang::parser::node::try_node::TryNode;
+use crate::lang::parser::node::type_node::TypeNode;
+use crate::lang::parser::node::unary_node::UnaryNode;
+use crate::lang::parser::node::var_access_node::VarAccessNode;
+use crate::lang::parser::node::var_assign_node::VarAssignNode;
+use crate::lang::parser::node::void_node::VoidNode;
+use crate::lang::parser::node::while_node::WhileNode;
 
+mod methods;
 pub mod primitive;
 mod scope;
 
-pub struct Interpreter<'a> {
-    scope: Scope<'a>,
+// interrupts unwind the evaluation up to the construct that handles them, e.g. a return up to the
+// function call it returns from, errors go all the way up to whoever called interpret
+pub enum Interrupt {
+    Return(Primitive),
+    Break,
+    Continue,
+    Error(GlassError),
+}
+
+impl From<GlassError> for Interrupt {
+    fn from(error: GlassError) -> Self {
+        Interrupt::Error(error)
+    }
+}
+
+// the outcome of a single iteration of a loop body
+enum Iteration {
+    Value(Primitive),
+    Continue,
+    Break,
 }
 
-impl<'a> Interpreter<'a> {
-    pub fn new(parent: Option<Rc<RefCell<Scope<'a>>>>, scope_name: &'a str) -> Self {
+pub type VisitResult = Result<Primitive, Interrupt>;
+
+pub const DEFAULT_MAX_DEPTH: usize = 1000;
+
+// the name anonymous functions are shown with, e.g. in tracebacks
+const ANONYMOUS_FUNCTION_NAME: &str = "<anonymous>";
+
+// when less than RED_ZONE bytes of native stack are left, evaluation continues on a new STACK_SIZE
+// segment allocated on the heap, so deep recursion hits the max depth instead of overflowing the stack
+pub(crate) const RED_ZONE: usize = 128 * 1024;
+pub(crate) const STACK_SIZE: usize = 4 * 1024 * 1024;
+
+pub struct Interpreter {
+    scope: Rc<RefCell<Scope>>,
+    global: Rc<RefCell<Scope>>,
+    call_stack: Vec<Frame>, // the glass functions currently being called, used for tracebacks
+    max_depth: usize,
+}
+
+impl Interpreter {
+    pub fn new(parent: Option<Rc<RefCell<Scope>>>, max_depth: usize) -> Self {
+        let global = Rc::new(RefCell::new(Scope::new(parent)));
+
         Interpreter {
-            scope: Scope::new(None, scope_name),
+            scope: Rc::clone(&global),
+            global,
+            call_stack: Vec::new(),
+            max_depth,
+        }
+    }
+
+    pub fn interpret(&mut self, node: &dyn Node) -> Result<Primitive, GlassError> {
+        let result = self.visit(node);
+        Self::finish(result)
+    }
+
+    // calls a function value from outside of a call node, e.g. from the host program (which has no call
+    // site) or a method taking a callback
+    pub fn call_value(&mut self, callee: &Primitive, args: Vec<Primitive>, call_site: Option<Position>) -> Result<Primitive, GlassError> {
+        match callee {
+            Primitive::Function(function) => {
+                let result = self.call_function(function, args, call_site);
+                Self::finish(result)
+            }
+            Primitive::NativeFunction(function) => Self::call_native(function, args, call_site),
+            callee => Err(GlassError::new(ErrorType::InvalidCall(callee.get_type().get_name()), call_site)),
+        }
+    }
+
+    pub fn get_global(&self, name: &str) -> Option<Primitive> {
+        self.global.borrow().get(name).map(|value| value.borrow().clone())
+    }
+
+    pub fn set_global(&mut self, name: &str, value: Primitive) {
+        self.global.borrow_mut().declare(name, value);
+    }
+
+    // functions keep the scope they were defined in alive, and global functions are stored in that same
+    // scope. the cycle is broken by removing every global once nothing will run in the scope anymore
+    pub fn clear_globals(&mut self) {
+        let globals = self.global.borrow_mut().clear();
+        drop(globals);
+    }
+
+    // the parser only allows return inside of functions and break and continue inside of loops, so
+    // errors are the only interrupts which should reach this far
+    fn finish(result: VisitResult) -> Result<Primitive, GlassError> {
+        match result {
+            Ok(value) | Err(Interrupt::Return(value)) => Ok(value),
+            Err(Interrupt::Break) | Err(Interrupt::Continue) => Ok(Primitive::Void),
+            Err(Interrupt::Error(error)) => Err(error),
+        }
+    }
+
+    fn visit(&mut self, node: &dyn Node) -> VisitResult {
+        stacker::maybe_grow(RED_ZONE, STACK_SIZE, || node.visit(self))
+    }
+
+    // a block evaluates to the value of its last statement, which is what the REPL prints
+    pub fn visit_block_node(&mut self, node: &BlockNode) -> VisitResult {
+        let mut result = Primitive::Void;
+
+        for statement in node.get_statements() {
+            result = self.visit(statement.as_ref())?;
+        }
+
+        Ok(result)
+    }
+
+    pub fn visit_number_node(&mut self, node: &NumberNode) -> VisitResult {
+        Ok(Primitive::Number(node.get_value()))
+    }
+
+    pub fn visit_string_node(&mut self, node: &StringNode) -> VisitResult {
+        Ok(Primitive::String(node.get_value().to_string()))
+    }
+
+    // every part is evaluated in the current scope and inserted the way it would be printed
+    pub fn visit_interpolation_node(&mut self, node: &InterpolationNode) -> VisitResult {
+        let mut result = String::new();
+
+        for part in node.get_parts() {
+            result.push_str(&self.visit(part.as_ref())?.to_string());
+        }
+
+        Ok(Primitive::String(result))
+    }
+
+    pub fn visit_type_node(&mut self, node: &TypeNode) -> VisitResult {
+        Ok(Primitive::TypePrimitive(node.get_value()))
+    }
+
+    pub fn visit_bool_node(&mut self, node: &BoolNode) -> VisitResult {
+        Ok(Primitive::Bool(node.get_value()))
+    }
+
+    pub fn visit_void_node(&mut self, _node: &VoidNode) -> VisitResult {
+        Ok(Primitive::Void)
+    }
+
+    pub fn visit_var_access_node(&mut self, node: &VarAccessNode) -> VisitResult {
+        let name = node.get_name();
+
+        match self.scope.borrow().get(&name) {
+            Some(value) => Ok(value.borrow().clone()),
+            None => Err(self.undefined_variable(name, node.get_token()).into()),
+        }
+    }
+
+    // suggests the closest name in scope, assuming the undefined one is a typo
+    fn undefined_variable(&self, name: String, token: &Token) -> GlassError {
+        let names = self.scope.borrow().get_names();
+        let suggestion = suggestion::did_you_mean(&name, names.iter().map(String::as_str)).map(str::to_string);
+        let error = GlassError::new(ErrorType::NoDefiningScope(name), Some(token.take_pos()));
+
+        match suggestion {
+            Some(suggestion) => error.with_help(format!("did you mean '{}'?", suggestion)),
+            None => error,
+        }
+    }
+
+    // assignments are statements, so they evaluate to void rather than the assigned value
+    pub fn visit_var_assign_node(&mut self, node: &VarAssignNode) -> VisitResult {
+        let name = node.get_name();
+        let op = node.get_op();
+        let mut value = self.visit(node.get_value())?;
+
+        if let Some(op_type) = op.get_type().get_compound_op() {
+            let current = match self.scope.borrow().get(&name) {
+                Some(current) => current.borrow().clone(),
+                None => return Err(self.undefined_variable(name, node.get_token()).into()),
+            };
+
+            value = self.binary_operation(op_type, op, current, value)?;
+        }
+
+        // `x = 1` always sets a variable of the current scope, `x += 1` updates whichever scope defines x
+        if op.get_type().get_compound_op().is_some() {
+            self.scope.borrow_mut().assign(&name, value);
+        } else {
+            self.scope.borrow_mut().declare(&name, value);
+        }
+
+        Ok(Primitive::Void)
+    }
+
+    pub fn visit_list_node(&mut self, node: &ListNode) -> VisitResult {
+        let mut items = Vec::with_capacity(node.get_elements().len());
+
+        for element in node.get_elements() {
+            items.push(self.visit(element.as_ref())?);
+        }
+
+        Ok(Primitive::new_list(items))
+    }
+
+    pub fn visit_dict_node(&mut self, node: &DictNode) -> VisitResult {
+        let mut entries = IndexMap::with_capacity(node.get_entries().len());
+
+        for (key_node, value_node) in node.get_entries() {
+            let key = self.visit(key_node.as_ref())?;
+            self.check_hashable(&key, key_node.as_ref())?;
+            let value = self.visit(value_node.as_ref())?;
+            entries.insert(key, value);
+        }
+
+        Ok(Primitive::new_dictionary(entries))
+    }
+
+    pub fn visit_index_node(&mut self, node: &IndexNode) -> VisitResult {
+        let target = self.visit(node.get_target())?;
+        let index = self.visit(node.get_index())?;
+
+        match target {
+            Primitive::List(items) => {
+                let items = items.borrow();
+                let index = self.resolve_index(&index, items.len(), node.get_index())?;
+                Ok(items[index].clone())
+            }
+            Primitive::Dictionary(entries) => {
+                self.check_hashable(&index, node.get_index())?;
+
+                match entries.borrow().get(&index) {
+                    Some(value) => Ok(value.clone()),
+                    None => {
+                        raise_error!(ErrorType::UnknownKey(index.to_string()), node.get_index().get_token().take_pos());
+                    }
+                }
+            }
+            // strings are indexed by character, giving a string of that one character
+            Primitive::String(value) => {
+                let index = self.resolve_index(&index, value.chars().count(), node.get_index())?;
+                Ok(Primitive::String(value.chars().nth(index).unwrap().to_string()))
+            }
+            _ => {
+                raise_error!(ErrorType::NoOperatorDefinition("[]"), node.get_token().take_pos());
+            }
+        }
+    }
+
+    pub fn visit_index_assign_node(&mut self, node: &IndexAssignNode) -> VisitResult {
+        let target = self.visit(node.get_target())?;
+        let index = self.visit(node.get_index())?;
+        let mut value = self.visit(node.get_value())?;
+        let op = node.get_op();
+
+        match target {
+            Primitive::List(items) => {
+                let index = self.resolve_index(&index, items.borrow().len(), node.get_index())?;
+
+                if let Some(op_type) = op.get_type().get_compound_op() {
+                    let current = items.borrow()[index].clone();
+                    value = self.binary_operation(op_type, op, current, value)?;
+                }
+
+                items.borrow_mut()[index] = value;
+                Ok(Primitive::Void)
+            }
+            Primitive::Dictionary(entries) => {
+                self.check_hashable(&index, node.get_index())?;
+
+                if let Some(op_type) = op.get_type().get_compound_op() {
+                    let current = match entries.borrow().get(&index) {
+                        Some(current) => current.clone(),
+                        None => {
+                            raise_error!(ErrorType::UnknownKey(index.to_string()), node.get_index().get_token().take_pos());
+                        }
+                    };
+
+                    value = self.binary_operation(op_type, op, current, value)?;
+                }
+
+                entries.borrow_mut().insert(index, value);
+                Ok(Primitive::Void)
+            }
+            _ => {
+                raise_error!(ErrorType::NoOperatorDefinition("[]="), node.get_token().take_pos());
+            }
+        }
+    }
+
+    pub fn visit_delete_node(&mut self, node: &DeleteNode) -> VisitResult {
+        let target = self.visit(node.get_target())?;
+        let index = self.visit(node.get_index())?;
+
+        match target {
+            Primitive::List(items) => {
+                let index = self.resolve_index(&index, items.borrow().len(), node.get_index())?;
+                items.borrow_mut().remove(index);
+            }
+            Primitive::Dictionary(entries) => {
+                self.check_hashable(&index, node.get_index())?;
+
+                if entries.borrow_mut().shift_remove(&index).is_none() {
+                    raise_error!(ErrorType::UnknownKey(index.to_string()), node.get_index().get_token().take_pos());
+                }
+            }
+            _ => {
+                raise_error!(ErrorType::NoOperatorDefinition("delete"), node.get_token().take_pos());
+            }
+        }
+
+        Ok(Primitive::Void)
+    }
+
+    fn check_hashable(&self, key: &Primitive, node: &dyn Node) -> Result<(), GlassError> {
+        if !key.is_hashable() {
+            raise_error!(ErrorType::Unhashable(key.get_type().get_name()), node.get_token().take_pos());
+        }
+
+        Ok(())
+    }
+
+    // slices are copies and their bounds are clamped to the list, so unlike indexing they never fail
+    pub fn visit_slice_node(&mut self, node: &SliceNode) -> VisitResult {
+        match self.visit(node.get_target())? {
+            Primitive::List(items) => {
+                let (start, end) = self.resolve_slice(node, items.borrow().len())?;
+                Ok(Primitive::new_list(items.borrow()[start..end].to_vec()))
+            }
+            // strings are sliced by character
+            Primitive::String(value) => {
+                let (start, end) = self.resolve_slice(node, value.chars().count())?;
+                Ok(Primitive::String(value.chars().skip(start).take(end - start).collect()))
+            }
+            _ => {
+                raise_error!(ErrorType::NoOperatorDefinition("[:]"), node.get_token().take_pos());
+            }
+        }
+    }
+
+    // the range a slice covers, empty if the end is before the start
+    fn resolve_slice(&mut self, node: &SliceNode, len: usize) -> Result<(usize, usize), Interrupt> {
+        let start = match node.get_start() {
+            Some(start) => self.resolve_bound(start, len)?,
+            None => 0,
+        };
+        let end = match node.get_end() {
+            Some(end) => self.resolve_bound(end, len)?,
+            None => len,
+        };
+
+        Ok((start, end.max(start)))
+    }
+
+    fn resolve_index(&self, index: &Primitive, len: usize, node: &dyn Node) -> Result<usize, GlassError> {
+        index.to_index(len).map_err(|error| error.with_position_if_missing(Some(node.get_token().take_pos())))
+    }
+
+    fn resolve_bound(&mut self, node: &dyn Node, len: usize) -> Result<usize, Interrupt> {
+        let bound = self.visit_number(node)?;
+
+        if bound.fract() != 0.0 {
+            raise_error!(ErrorType::InvalidIndex(bound), node.get_token().take_pos());
+        }
+
+        let bound = if bound < 0.0 { bound + len as f64 } else { bound };
+        Ok(bound.max(0.0).min(len as f64) as usize)
+    }
+
+    pub fn visit_func_def_node(&mut self, node: &FuncDefNode) -> VisitResult {
+        let name = node.get_name();
+        let function = Function::new(name.clone(), node.get_params(), node.get_body(), Rc::clone(&self.scope));
+
+        self.scope.borrow_mut().declare(&name, Primitive::Function(Rc::new(function)));
+        Ok(Primitive::Void)
+    }
+
+    // anonymous functions capture the scope they're created in just like named ones
+    pub fn visit_function_node(&mut self, node: &FunctionNode) -> VisitResult {
+        let function = Function::new(String::from(ANONYMOUS_FUNCTION_NAME), node.get_params(), node.get_body(), Rc::clone(&self.scope));
+        Ok(Primitive::Function(Rc::new(function)))
+    }
+
+    pub fn visit_call_node(&mut self, node: &CallNode) -> VisitResult {
+        let callee = self.visit(node.get_callee())?;
+        let mut args = Vec::with_capacity(node.get_args().len());
+
+        for arg in node.get_args() {
+            args.push(self.visit(arg.as_ref())?);
+        }
+
+        match callee {
+            Primitive::Function(function) => self.call_function(&function, args, Some(node.get_token().take_pos())),
+            Primitive::NativeFunction(function) => Ok(Self::call_native(&function, args, Some(node.get_token().take_pos()))?),
+            callee => {
+                raise_error!(ErrorType::InvalidCall(callee.get_type().get_name()), node.get_token().take_pos());
+            }
         }
     }
 
-    fn visit(&self, node: (/*Node*/)) -> (/*Primitive*/) {
-        todo!()
+    pub fn visit_method_call_node(&mut self, node: &MethodCallNode) -> VisitResult {
+        let target = self.visit(node.get_target())?;
+        let mut args = Vec::with_capacity(node.get_args().len());
+
+        for arg in node.get_args() {
+            args.push(self.visit(arg.as_ref())?);
+        }
+
+        let pos = node.get_token().take_pos();
+        let result = methods::call_method(self, target, &node.get_name(), args, pos.clone());
+
+        Ok(result.map_err(|error| error.with_position_if_missing(Some(pos)))?)
+    }
+
+    // attributes are the string keys of a dictionary, no other type has any
+    pub fn visit_attribute_node(&mut self, node: &AttributeNode) -> VisitResult {
+        let target = self.visit(node.get_target())?;
+        let name = node.get_name();
+        let pos = node.get_token().take_pos();
+
+        match target {
+            Primitive::Dictionary(entries) => match entries.borrow().get(&Primitive::String(name.clone())) {
+                Some(value) => Ok(value.clone()),
+                None => {
+                    let error = GlassError::new(ErrorType::UnknownKey(name.clone()), Some(pos));
+                    let keys = entries.borrow().keys().filter_map(|key| match key {
+                        Primitive::String(key) => Some(key.clone()),
+                        _ => None,
+                    }).collect::<Vec<_>>();
+
+                    Err(match suggestion::did_you_mean(&name, keys.iter().map(String::as_str)) {
+                        Some(key) => error.with_help(format!("did you mean '{}'?", key)),
+                        None => error,
+                    }.into())
+                }
+            },
+            target => {
+                let error = GlassError::new(ErrorType::NoAttribute(target.get_type().get_name(), name.clone()), Some(pos));
+
+                // most likely a method call missing its parentheses
+                Err(if methods::has_method(target.get_type(), &name) {
+                    error.with_help(format!("'{}' is a method, call it with '.{}()'", name, name))
+                } else {
+                    error
+                }.into())
+            }
+        }
     }
 
-    fn visit_bin_op_node(&self, node: (/*BinOpNode*/)) -> (/*Primitive*/) {
-        todo!()
+    pub fn visit_return_node(&mut self, node: &ReturnNode) -> VisitResult {
+        let value = match node.get_value() {
+            Some(value) => self.visit(value)?,
+            None => Primitive::Void,
+        };
+
+        Err(Interrupt::Return(value))
     }
-}
\ No newline at end of file
+
+    fn call_function(&mut self, function: &Function, args: Vec<Primitive>, call_site: Option<Position>) -> VisitResult {
+        let params = function.get_params();
+
+        if params.len() != args.len() {
+            let error = GlassError::new(ErrorType::UnexpectedArgCount(params.len(), args.len()), call_site);
+            return Err(error.with_note(format!("'{}' is defined as {}({})", function.get_name(), function.get_name(), params.join(", "))).into());
+        }
+
+        if self.call_stack.len() >= self.max_depth {
+            let error = GlassError::new(ErrorType::RecursionLimit(self.max_depth), call_site);
+            return Err(error.with_help(String::from("make sure the recursion has a base case, or raise the limit with --max-depth")).into());
+        }
+
+        let mut scope = Scope::new(Some(function.get_scope()));
+
+        for (param, arg) in params.iter().zip(args) {
+            scope.declare(param, arg);
+        }
+
+        self.call_stack.push(Frame::new(function.get_name().to_string(), call_site));
+        let previous = mem::replace(&mut self.scope, Rc::new(RefCell::new(scope)));
+        let result = self.visit(function.get_body());
+        self.scope = previous;
+
+        // falling off the end of a function body returns void
+        let result = match result {
+            Ok(_) => Ok(Primitive::Void),
+            Err(Interrupt::Return(value)) => Ok(value),
+            Err(Interrupt::Error(mut error)) => {
+                error.attach_traceback(&self.call_stack);
+                Err(Interrupt::Error(error))
+            }
+            Err(interrupt) => Err(interrupt),
+        };
+
+        self.call_stack.pop();
+        result
+    }
+
+    // native functions aren't put on the call stack, any error they return is reported at the call
+    fn call_native(function: &NativeFunction, args: Vec<Primitive>, call_site: Option<Position>) -> Result<Primitive, GlassError> {
+        function.get_arity().check(function.get_name(), args.len())
+            .and_then(|_| function.call(args))
+            .map_err(|error| error.with_position_if_missing(call_site))
+    }
+
+    pub fn visit_if_node(&mut self, node: &IfNode) -> VisitResult {
+        if self.visit_condition(node.get_condition())? {
+            self.visit(node.get_body())
+        } else {
+            match node.get_else_body() {
+                Some(else_body) => self.visit(else_body),
+                None => Ok(Primitive::Void),
+            }
+        }
+    }
+
+    pub fn visit_for_node(&mut self, node: &ForNode) -> VisitResult {
+        let name = node.get_var();
+        let start = self.visit_number(node.get_start())?;
+        let end = self.visit_number(node.get_end())?;
+
+        let step = match node.get_step() {
+            Some(step_node) => {
+                let step = self.visit_number(step_node)?;
+
+                if step == 0.0 {
+                    raise_error!(ErrorType::InvalidStep, step_node.get_token().take_pos());
+                }
+
+                step
+            }
+            None => 1.0,
+        };
+
+        let mut results = Vec::new();
+        let mut current = start;
+
+        while (step > 0.0 && current < end) || (step < 0.0 && current > end) {
+            self.scope.borrow_mut().declare(&name, Primitive::Number(current));
+            current += step;
+
+            match self.visit_iteration(node.get_body())? {
+                Iteration::Value(value) if node.collects() => results.push(value),
+                Iteration::Break => break,
+                _ => {}
+            }
+        }
+
+        Ok(if node.collects() { Primitive::new_list(results) } else { Primitive::Void })
+    }
+
+    pub fn visit_for_in_node(&mut self, node: &ForInNode) -> VisitResult {
+        let name = node.get_var();
+
+        // iterate over a snapshot so the body can modify the list without affecting the loop
+        let items = match self.visit(node.get_iterable())? {
+            Primitive::List(items) => items.borrow().clone(),
+            Primitive::Dictionary(entries) => entries.borrow().keys().cloned().collect(),
+            Primitive::String(value) => value.chars().map(|c| Primitive::String(c.to_string())).collect(),
+            iterable => {
+                raise_error!(ErrorType::InvalidIteration(iterable.get_type().get_name()), node.get_iterable().get_token().take_pos());
+            }
+        };
+
+        let mut results = Vec::new();
+
+        for item in items {
+            self.scope.borrow_mut().declare(&name, item);
+
+            match self.visit_iteration(node.get_body())? {
+                Iteration::Value(value) if node.collects() => results.push(value),
+                Iteration::Break => break,
+                _ => {}
+            }
+        }
+
+        Ok(if node.collects() { Primitive::new_list(results) } else { Primitive::Void })
+    }
+
+    pub fn visit_while_node(&mut self, node: &WhileNode) -> VisitResult {
+        while self.visit_condition(node.get_condition())? {
+            if let Iteration::Break = self.visit_iteration(node.get_body())? {
+                break;
+            }
+        }
+
+        Ok(Primitive::Void)
+    }
+
+    pub fn visit_break_node(&mut self, _node: &BreakNode) -> VisitResult {
+        Err(Interrupt::Break)
+    }
+
+    pub fn visit_continue_node(&mut self, _node: &ContinueNode) -> VisitResult {
+        Err(Interrupt::Continue)
+    }
+
+    // the error is bound to the catch variable, finally runs however the try and catch bodies were left
+    // (an error, a return, a break...) unless it's left early itself, in which case that takes over
+    pub fn visit_try_node(&mut self, node: &TryNode) -> VisitResult {
+        let mut result = self.visit(node.get_body());
+
+        if let (Err(Interrupt::Error(error)), Some(catch_body)) = (&result, node.get_catch_body()) {
+            if let Some(name) = node.get_catch_var() {
+                self.scope.borrow_mut().declare(&name, self.error_value(error));
+            }
+
+            result = self.visit(catch_body);
+        }
+
+        if let Some(finally_body) = node.get_finally_body() {
+            self.visit(finally_body)?;
+        }
+
+        result
+    }
+
+    // a thrown string becomes the message of a plain `Error`, a thrown dictionary is thrown as is which
+    // allows custom error types and rethrowing caught errors
+    pub fn visit_throw_node(&mut self, node: &ThrowNode) -> VisitResult {
+        let pos = node.get_token().take_pos();
+
+        let value = match self.visit(node.get_value())? {
+            Primitive::String(message) => Self::new_error_value("Error", message, Some(&pos), &self.call_stack),
+            value @ Primitive::Dictionary(_) => value,
+            value => {
+                raise_error!(ErrorType::UnexpectedType(Type::String.get_name(), value.get_type().get_name()), node.get_value().get_token().take_pos());
+            }
+        };
+
+        raise_error!(ErrorType::Thrown(value), pos);
+    }
+
+    // the value a caught error is bound to, thrown values are caught as they were thrown. an error caught
+    // in the function it occurred in has no traceback yet, its stack is the current one
+    fn error_value(&self, error: &GlassError) -> Primitive {
+        match error.get_error_type() {
+            ErrorType::Thrown(value) => value.clone(),
+            error_type => {
+                let traceback = error.get_traceback().unwrap_or(&self.call_stack);
+                Self::new_error_value(error_type.into(), error_type.to_string(), error.get_position(), traceback)
+            }
+        }
+    }
+
+    fn new_error_value(error_type: &str, message: String, pos: Option<&Position>, traceback: &[Frame]) -> Primitive {
+        let mut entries = IndexMap::new();
+        let mut field = |name: &str, value| entries.insert(Primitive::String(name.to_string()), value);
+
+        field("type", Primitive::String(error_type.to_string()));
+        field("message", Primitive::String(message));
+        field("file", pos.map_or(Primitive::Void, |pos| Primitive::String(pos.get_filename().to_string())));
+        field("line", pos.map_or(Primitive::Void, |pos| Primitive::Number(pos.get_row() as f64)));
+        field("column", pos.map_or(Primitive::Void, |pos| Primitive::Number(pos.get_column() as f64)));
+        field("traceback", Primitive::new_list(traceback.iter().map(Self::frame_value).collect()));
+
+        Primitive::new_dictionary(entries)
+    }
+
+    fn frame_value(frame: &Frame) -> Primitive {
+        let pos = frame.get_call_site();
+        let mut entries = IndexMap::new();
+
+        entries.insert(Primitive::String(String::from("function")), Primitive::String(frame.get_name().to_string()));
+        entries.insert(Primitive::String(String::from("file")), pos.map_or(Primitive::Void, |pos| Primitive::String(pos.get_filename().to_string())));
+        entries.insert(Primitive::String(String::from("line")), pos.map_or(Primitive::Void, |pos| Primitive::Number(pos.get_row() as f64)));
+        entries.insert(Primitive::String(String::from("column")), pos.map_or(Primitive::Void, |pos| Primitive::Number(pos.get_column() as f64)));
+
+        Primitive::new_dictionary(entries)
+    }
+
+    // continue skips the rest of the body, in a collecting loop that also means no value for the iteration
+    fn visit_iteration(&mut self, body: &dyn Node) -> Result<Iteration, Interrupt> {
+        match self.visit(body) {
+            Ok(value) => Ok(Iteration::Value(value)),
+            Err(Interrupt::Continue) => Ok(Iteration::Continue),
+            Err(Interrupt::Break) => Ok(Iteration::Break),
+            Err(interrupt) => Err(interrupt),
+        }
+    }
+
+    fn visit_number(&mut self, node: &dyn Node) -> Result<f64, Interrupt> {
+        match self.visit(node)? {
+            Primitive::Number(value) => Ok(value),
+            value => {
+                raise_error!(ErrorType::UnexpectedType(Type::Number.get_name(), value.get_type().get_name()), node.get_token().take_pos());
+            }
+        }
+    }
+
+    fn visit_condition(&mut self, node: &dyn Node) -> Result<bool, Interrupt> {
+        let value = self.visit(node)?;
+        Ok(Self::truthiness(&value, node)?)
+    }
+
+    // the node is the one the value came from, an error is reported there if it has no truth value
+    fn truthiness(value: &Primitive, node: &dyn Node) -> Result<bool, GlassError> {
+        match value.is_truthy() {
+            Some(truthy) => Ok(truthy),
+            None => {
+                raise_error!(ErrorType::UnexpectedType(Type::Bool.get_name(), value.get_type().get_name()), node.get_token().take_pos());
+            }
+        }
+    }
+
+    pub fn visit_unary_node(&mut self, node: &UnaryNode) -> VisitResult {
+        let value = self.visit(node.get_expr())?;
+        let op = node.get_token();
+
+        match (op.get_type(), value) {
+            (TokenType::Not, value) => Ok(Primitive::Bool(!Self::truthiness(&value, node.get_expr())?)),
+            (TokenType::Minus, Primitive::Number(value)) => Ok(Primitive::Number(-value)),
+            (TokenType::Plus, Primitive::Number(value)) => Ok(Primitive::Number(value)),
+            (_, value) => {
+                raise_error!(ErrorType::InvalidInversion(value.get_type().get_name()), op.take_pos());
+            }
+        }
+    }
+
+    pub fn visit_bin_op_node(&mut self, node: &BinOpNode) -> VisitResult {
+        let op = node.get_token();
+
+        if matches!(op.get_type(), TokenType::And | TokenType::Or) {
+            return self.visit_logical(node);
+        }
+
+        let left = self.visit(node.get_left())?;
+        let right = self.visit(node.get_right())?;
+
+        Ok(self.binary_operation(op.get_type(), op, left, right)?)
+    }
+
+    // the right operand is only evaluated if the left one doesn't decide the result, which is the operand
+    // that decided it rather than a bool, e.g. `name or "anonymous"`
+    fn visit_logical(&mut self, node: &BinOpNode) -> VisitResult {
+        let left = self.visit(node.get_left())?;
+        let truthy = Self::truthiness(&left, node.get_left())?;
+
+        if truthy == (node.get_token().get_type() == TokenType::Or) {
+            Ok(left)
+        } else {
+            self.visit(node.get_right())
+        }
+    }
+
+    // op_type is passed separately from the token since compound assignments use the token of their
+    // compound operator (+=) but the semantics of the plain one (+)
+    fn binary_operation(&self, op_type: TokenType, op: &Token, left: Primitive, right: Primitive) -> Result<Primitive, GlassError> {
+        Ok(match (op_type, left, right) {
+            // equality is defined between every pair of types
+            (TokenType::EqualEqual, left, right) => Primitive::Bool(left == right),
+            (TokenType::NotEqual, left, right) => Primitive::Bool(left != right),
+            // `x is num` checks the type, unless x is a type itself in which case the types are compared
+            (TokenType::Is, Primitive::TypePrimitive(left), Primitive::TypePrimitive(right)) => Primitive::Bool(left == right),
+            (TokenType::Is, value, Primitive::TypePrimitive(value_type)) => Primitive::Bool(value.get_type() == value_type),
+            (TokenType::Is, left, right) => Primitive::Bool(left.is_identical(&right)),
+            (TokenType::In, item, Primitive::List(items)) => Primitive::Bool(items.borrow().contains(&item)),
+            // unhashable values can never be keys, so they're simply not in the dictionary
+            (TokenType::In, key, Primitive::Dictionary(entries)) => Primitive::Bool(key.is_hashable() && entries.borrow().contains_key(&key)),
+            (TokenType::In, Primitive::String(part), Primitive::String(value)) => Primitive::Bool(value.contains(&part)),
+            (op_type, Primitive::Number(left), Primitive::Number(right)) => match op_type {
+                TokenType::Plus => Primitive::Number(left + right),
+                TokenType::Minus => Primitive::Number(left - right),
+                TokenType::Times => Primitive::Number(left * right),
+                TokenType::Divide | TokenType::Mod if right == 0.0 => {
+                    raise_error!(ErrorType::DivisionByZero, op.take_pos());
+                }
+                TokenType::Divide => Primitive::Number(left / right),
+                TokenType::Mod => Primitive::Number(left % right),
+                TokenType::Pow => Primitive::Number(left.powf(right)),
+                TokenType::LessThan => Primitive::Bool(left < right),
+                TokenType::LessThanEqual => Primitive::Bool(left <= right),
+                TokenType::GreaterThan => Primitive::Bool(left > right),
+                TokenType::GreaterThanEqual => Primitive::Bool(left >= right),
+                _ => {
+                    raise_error!(ErrorType::NoOperatorDefinition(op_type.get_symbol()), op.take_pos());
+                }
+            },
+            (TokenType::Plus, Primitive::String(left), Primitive::String(right)) => Primitive::String(left + &right),
+            (TokenType::Plus, Primitive::List(left), Primitive::List(right)) => {
+                let mut items = left.borrow().clone();
+                items.extend(right.borrow().iter().cloned());
+                Primitive::new_list(items)
+            }
+            // the items are shallow copies, `[[0]] * 2` holds the same inner list twice
+            (TokenType::Times, Primitive::List(items), Primitive::Number(count))
+            | (TokenType::Times, Primitive::Number(count), Primitive::List(items)) if count >= 0.0 && count.fract() == 0.0 => {
+                let items = items.borrow();
+                let mut repeated = Vec::new();
+
+                // reserving up front turns a count too large to allocate into an error instead of an abort
+                match items.len().checked_mul(count as usize) {
+                    Some(len) if repeated.try_reserve_exact(len).is_ok() => repeated.extend(items.iter().cloned().cycle().take(len)),
+                    _ => {
+                        raise_error!(ErrorType::RepetitionTooLarge(count), op.take_pos());
+                    }
+                }
+
+                Primitive::new_list(repeated)
+            }
+            (TokenType::Times, Primitive::String(value), Primitive::Number(count))
+            | (TokenType::Times, Primitive::Number(count), Primitive::String(value)) if count >= 0.0 && count.fract() == 0.0 => {
+                let mut repeated = String::new();
+
+                match value.len().checked_mul(count as usize) {
+                    Some(0) => {}
+                    Some(len) if repeated.try_reserve_exact(len).is_ok() => repeated.extend(std::iter::repeat_n(value.as_str(), count as usize)),
+                    _ => {
+                        raise_error!(ErrorType::RepetitionTooLarge(count), op.take_pos());
+                    }
+                }
+
+                Primitive::String(repeated)
+            }
+            (op_type, Primitive::String(left), Primitive::String(right)) => match op_type {
+                TokenType::LessThan => Primitive::Bool(left < right),
+                TokenType::LessThanEqual => Primitive::Bool(left <= right),
+                TokenType::GreaterThan => Primitive::Bool(left > right),
+                TokenType::GreaterThanEqual => Primitive::Bool(left >= right),
+                _ => {
+                    raise_error!(ErrorType::NoOperatorDefinition(op_type.get_symbol()), op.take_pos());
+                }
+            },
+            (op_type, left, right) => {
+                let error = GlassError::new(ErrorType::NoOperatorDefinition(op_type.get_symbol()), Some(op.take_pos()));
+                return Err(error.with_note(format!("left operand is '{}', right operand is '{}'", left.get_type().get_name(), right.get_type().get_name())));
+            }
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::errorsystem::error_type::ErrorType;
+use crate::errorsystem::GlassError;
+use crate::lang::interpreter::primitive::{Primitive, Type};
+
+// conversions between rust values and primitives, used by programs embedding glass to pass values in
+// and out of the engine. every rust number becomes a glass num (an f64), so large integers lose precision
+
+impl From<f64> for Primitive {
+    fn from(value: f64) -> Self {
+        Primitive::Number(value)
+    }
+}
+
+impl From<i32> for Primitive {
+    fn from(value: i32) -> Self {
+        Primitive::Number(value as f64)
+    }
+}
+
+impl From<i64> for Primitive {
+    fn from(value: i64) -> Self {
+        Primitive::Number(value as f64)
+    }
+}
+
+impl From<usize> for Primitive {
+    fn from(value: usize) -> Self {
+        Primitive::Number(value as f64)
+    }
+}
+
+impl From<bool> for Primitive {
+    fn from(value: bool) -> Self {
+        Primitive::Bool(value)
+    }
+}
+
+impl From<String> for Primitive {
+    fn from(value: String) -> Self {
+        Primitive::String(value)
+    }
+}
+
+impl From<&str> for Primitive {
+    fn from(value: &str) -> Self {
+        Primitive::String(value.to_string())
+    }
+}
+
+impl From<()> for Primitive {
+    fn from(_: ()) -> Self {
+        Primitive::Void
+    }
+}
+
+impl From<Type> for Primitive {
+    fn from(value: Type) -> Self {
+        Primitive::TypePrimitive(value)
+    }
+}
+
+// None becomes void
+impl<T: Into<Primitive>> From<Option<T>> for Primitive {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Primitive::Void, Into::into)
+    }
+}
+
+impl<T: Into<Primitive>> From<Vec<T>> for Primitive {
+    fn from(value: Vec<T>) -> Self {
+        Primitive::new_list(value.into_iter().map(Into::into).collect())
+    }
+}
+
+// only string keys, since every string is hashable the result is always a valid dictionary
+impl<V: Into<Primitive>> From<HashMap<String, V>> for Primitive {
+    fn from(value: HashMap<String, V>) -> Self {
+        Primitive::new_dictionary(value.into_iter().map(|(key, value)| (Primitive::String(key), value.into())).collect())
+    }
+}
+
+fn unexpected_type(expected: Type, value: &Primitive) -> GlassError {
+    GlassError::new(ErrorType::UnexpectedType(expected.get_name(), value.get_type().get_name()), None)
+}
+
+impl TryFrom<Primitive> for f64 {
+    type Error = GlassError;
+
+    fn try_from(value: Primitive) -> Result<Self, Self::Error> {
+        match value {
+            Primitive::Number(value) => Ok(value),
+            value => Err(unexpected_type(Type::Number, &value)),
+        }
+    }
+}
+
+impl TryFrom<Primitive> for bool {
+    type Error = GlassError;
+
+    fn try_from(value: Primitive) -> Result<Self, Self::Error> {
+        match value {
+            Primitive::Bool(value) => Ok(value),
+            value => Err(unexpected_type(Type::Bool, &value)),
+        }
+    }
+}
+
+impl TryFrom<Primitive> for String {
+    type Error = GlassError;
+
+    fn try_from(value: Primitive) -> Result<Self, Self::Error> {
+        match value {
+            Primitive::String(value) => Ok(value),
+            value => Err(unexpected_type(Type::String, &value)),
+        }
+    }
+}
+
+impl TryFrom<Primitive> for () {
+    type Error = GlassError;
+
+    fn try_from(value: Primitive) -> Result<Self, Self::Error> {
+        match value {
+            Primitive::Void => Ok(()),
+            value => Err(unexpected_type(Type::Void, &value)),
+        }
+    }
+}
+
+// copies the items out of the list, so later changes to the glass list aren't seen by the vec
+impl<T: TryFrom<Primitive, Error = GlassError>> TryFrom<Primitive> for Vec<T> {
+    type Error = GlassError;
+
+    fn try_from(value: Primitive) -> Result<Self, Self::Error> {
+        match value {
+            Primitive::List(items) => items.borrow().iter().cloned().map(T::try_from).collect(),
+            value => Err(unexpected_type(Type::List, &value)),
+        }
+    }
+}
+
+// dictionaries with keys other than strings can't be converted
+impl<V: TryFrom<Primitive, Error = GlassError>> TryFrom<Primitive> for HashMap<String, V> {
+    type Error = GlassError;
+
+    fn try_from(value: Primitive) -> Result<Self, Self::Error> {
+        match value {
+            Primitive::Dictionary(entries) => entries.borrow().iter()
+                .map(|(key, value)| Ok((String::try_from(key.clone())?, V::try_from(value.clone())?)))
+                .collect(),
+            value => Err(unexpected_type(Type::Dictionary, &value)),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::lang::interpreter::scope::Scope;
+use crate::lang::parser::node::Node;
+
+pub struct Function {
+    name: String,
+    params: Vec<String>,
+    body: Rc<dyn Node>,
+    scope: Rc<RefCell<Scope>>, // the scope the function was defined in, each call's scope is a child of it
+}
+
+impl Function {
+    pub fn new(name: String, params: Vec<String>, body: Rc<dyn Node>, scope: Rc<RefCell<Scope>>) -> Self {
+        Self { name, params, body, scope }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_params(&self) -> &[String] {
+        &self.params
+    }
+
+    pub fn get_body(&self) -> &dyn Node {
+        self.body.as_ref()
+    }
+
+    pub fn get_scope(&self) -> Rc<RefCell<Scope>> {
+        Rc::clone(&self.scope)
+    }
+}
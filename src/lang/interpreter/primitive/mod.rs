@@ -1,16 +1,39 @@
-use std::collections::HashMap;
-use std::fmt::Display;
+use std::collections::HashSet;
+use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::rc::Rc;
 
+use indexmap::IndexMap;
+
+use crate::errorsystem::error_type::ErrorType;
+use crate::errorsystem::raise_error;
+use crate::errorsystem::GlassError;
+use crate::lang::interpreter::primitive::function::Function;
+use crate::lang::interpreter::primitive::native_function::NativeFunction;
+use crate::lang::interpreter::primitive::shared::{Contents, Dictionary, List, Shared};
+use crate::lang::interpreter::{RED_ZONE, STACK_SIZE};
+
+mod convert;
+pub mod function;
+pub mod native_function;
+pub mod shared;
+
+#[derive(Clone)]
 pub enum Primitive {
     String(String),
     Number(f64),
     Bool(bool),
-    Dictionary(HashMap<Primitive, Primitive>),
-    List(Vec<Primitive>),
+    // dictionaries and lists are shared, so mutations through one reference are seen by all of them
+    Dictionary(Rc<Dictionary>),
+    List(Rc<List>),
     TypePrimitive(Type),
+    Function(Rc<Function>),
+    NativeFunction(Rc<NativeFunction>),
     Void,
 }
 
+#[derive(Copy, Clone, PartialEq)]
 pub enum Type {
     String,
     Number,
@@ -18,6 +41,7 @@ pub enum Type {
     Dictionary,
     List,
     Type,
+    Function,
     Void,
 }
 
@@ -30,21 +54,257 @@ impl Type {
             Primitive::Dictionary(_) => Type::Dictionary,
             Primitive::List(_) => Type::List,
             Primitive::TypePrimitive(_) => Type::Type,
+            Primitive::Function(_) | Primitive::NativeFunction(_) => Type::Function,
             Primitive::Void => Type::Void,
         }
     }
+
+    // the name used for this type in Glass source code and error messages
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Type::String => "str",
+            Type::Number => "num",
+            Type::Bool => "bool",
+            Type::Dictionary => "dict",
+            Type::List => "list",
+            Type::Type => "type",
+            Type::Function => "function",
+            Type::Void => "void",
+        }
+    }
 }
 
-impl Display for Type {
+impl Primitive {
+    pub fn new_list(items: Vec<Primitive>) -> Primitive {
+        Primitive::List(Shared::new(items))
+    }
+
+    pub fn new_dictionary(entries: IndexMap<Primitive, Primitive>) -> Primitive {
+        Primitive::Dictionary(Shared::new(entries))
+    }
+
+    pub fn get_type(&self) -> Type {
+        Type::type_of(self)
+    }
+
+    // looks up a string key of a dictionary, None if it's missing or this isn't a dictionary
+    pub fn get_field(&self, name: &str) -> Option<Primitive> {
+        match self {
+            Primitive::Dictionary(entries) => entries.borrow().get(&Primitive::String(name.to_string())).cloned(),
+            _ => None,
+        }
+    }
+
+    // whether the primitive can be used as a dictionary key. the mutable containers can't since their
+    // hash would change along with their contents, NaN can't since it isn't equal to itself and so
+    // could never be looked up again
+    pub fn is_hashable(&self) -> bool {
+        match self {
+            Primitive::String(_) | Primitive::Bool(_) | Primitive::TypePrimitive(_) | Primitive::Void => true,
+            Primitive::Number(value) => !value.is_nan(),
+            Primitive::Dictionary(_) | Primitive::List(_) | Primitive::Function(_) | Primitive::NativeFunction(_) => false,
+        }
+    }
+
+    // whether both are the same value, lists, dictionaries and functions are only identical to themselves
+    // (not to a copy), everything else is identical to any equal value
+    pub fn is_identical(&self, other: &Primitive) -> bool {
+        match (self, other) {
+            (Primitive::List(a), Primitive::List(b)) => Rc::ptr_eq(a, b),
+            (Primitive::Dictionary(a), Primitive::Dictionary(b)) => Rc::ptr_eq(a, b),
+            _ => self == other,
+        }
+    }
+
+    // turns a (possibly negative) index into a position in a sequence of the given length
+    pub fn to_index(&self, len: usize) -> Result<usize, GlassError> {
+        let index = match self {
+            Primitive::Number(index) => *index,
+            index => {
+                raise_error!(ErrorType::UnexpectedType(Type::Number.get_name(), index.get_type().get_name()));
+            }
+        };
+
+        if index.fract() != 0.0 || !index.is_finite() {
+            raise_error!(ErrorType::InvalidIndex(index));
+        }
+
+        let resolved = if index < 0.0 { index + len as f64 } else { index };
+
+        if resolved < 0.0 || resolved >= len as f64 {
+            raise_error!(ErrorType::OutOfBounds(index as i64, len));
+        }
+
+        Ok(resolved as usize)
+    }
+
+    // false, void, 0 and empty strings, lists and dictionaries are falsy, anything else of those types is
+    // truthy. functions and types have no truth value (None), using one as a condition is almost always
+    // a forgotten call or comparison so it's reported instead of silently being true
+    pub fn is_truthy(&self) -> Option<bool> {
+        match self {
+            Primitive::Bool(value) => Some(*value),
+            Primitive::Number(value) => Some(*value != 0.0),
+            Primitive::String(value) => Some(!value.is_empty()),
+            Primitive::List(value) => Some(!value.borrow().is_empty()),
+            Primitive::Dictionary(value) => Some(!value.borrow().is_empty()),
+            Primitive::Void => Some(false),
+            Primitive::TypePrimitive(_) | Primitive::Function(_) | Primitive::NativeFunction(_) => None,
+        }
+    }
+}
+
+// identifies a list or dictionary while walking nested containers, which can contain themselves
+// (`a = [0]`, `a[0] = a`) since they're shared
+type ContainerId = *const ();
+
+fn container_id<T: Contents>(container: &Rc<Shared<T>>) -> ContainerId {
+    Rc::as_ptr(container) as ContainerId
+}
+
+// both walks recurse once per level of nesting, so like the interpreter they grow the native stack
+// when it runs low
+impl Primitive {
+    fn eq_visiting(&self, other: &Primitive, visiting: &mut HashSet<(ContainerId, ContainerId)>) -> bool {
+        match (self, other) {
+            (Primitive::List(a), Primitive::List(b)) => eq_containers(a, b, visiting, |a, b, visiting| {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.eq_visiting(b, visiting))
+            }),
+            (Primitive::Dictionary(a), Primitive::Dictionary(b)) => eq_containers(a, b, visiting, |a, b, visiting| {
+                a.len() == b.len() && a.iter().all(|(key, a)| b.get(key).is_some_and(|b| a.eq_visiting(b, visiting)))
+            }),
+            _ => self == other,
+        }
+    }
+
+    // a container already being printed further up is printed as `[...]` or `{...}`
+    fn fmt_visiting(&self, f: &mut std::fmt::Formatter, visiting: &mut HashSet<ContainerId>) -> std::fmt::Result {
+        let (id, open, close) = match self {
+            Primitive::List(value) => (container_id(value), "[", "]"),
+            Primitive::Dictionary(value) => (container_id(value), "{", "}"),
+            value => return Display::fmt(value, f),
+        };
+
+        if !visiting.insert(id) {
+            return write!(f, "{}...{}", open, close);
+        }
+
+        write!(f, "{}", open)?;
+
+        stacker::maybe_grow(RED_ZONE, STACK_SIZE, || match self {
+            Primitive::List(value) => {
+                for (i, item) in value.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    item.fmt_visiting(f, visiting)?;
+                }
+
+                Ok(())
+            }
+            Primitive::Dictionary(value) => {
+                for (i, (key, value)) in value.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    key.fmt_visiting(f, visiting)?;
+                    write!(f, ": ")?;
+                    value.fmt_visiting(f, visiting)?;
+                }
+
+                Ok(())
+            }
+            _ => Ok(()),
+        })?;
+
+        visiting.remove(&id);
+        write!(f, "{}", close)
+    }
+}
+
+// pairs of containers already being compared further up are assumed equal, any difference between
+// them is found where the comparison first reached them
+fn eq_containers<T: Contents>(a: &Rc<Shared<T>>, b: &Rc<Shared<T>>, visiting: &mut HashSet<(ContainerId, ContainerId)>,
+                              compare: impl FnOnce(&T, &T, &mut HashSet<(ContainerId, ContainerId)>) -> bool) -> bool {
+    let pair = (container_id(a), container_id(b));
+
+    if pair.0 == pair.1 || !visiting.insert(pair) {
+        return true;
+    }
+
+    let equal = stacker::maybe_grow(RED_ZONE, STACK_SIZE, || compare(&a.borrow(), &b.borrow(), visiting));
+    visiting.remove(&pair);
+    equal
+}
+
+// primitives of different types are never equal, so `1 == "1"` is simply false
+impl PartialEq for Primitive {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Primitive::String(a), Primitive::String(b)) => a == b,
+            (Primitive::Number(a), Primitive::Number(b)) => a == b,
+            (Primitive::Bool(a), Primitive::Bool(b)) => a == b,
+            (Primitive::List(_), Primitive::List(_)) | (Primitive::Dictionary(_), Primitive::Dictionary(_)) => self.eq_visiting(other, &mut HashSet::new()),
+            (Primitive::TypePrimitive(a), Primitive::TypePrimitive(b)) => a == b,
+            (Primitive::Function(a), Primitive::Function(b)) => Rc::ptr_eq(a, b),
+            (Primitive::NativeFunction(a), Primitive::NativeFunction(b)) => Rc::ptr_eq(a, b),
+            (Primitive::Void, Primitive::Void) => true,
+            _ => false,
+        }
+    }
+}
+
+// NaN is the only value that isn't equal to itself and it's never hashable, so for every primitive
+// that can end up as a dictionary key equality is reflexive
+impl Eq for Primitive {}
+
+impl Hash for Primitive {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+
+        match self {
+            Primitive::String(value) => value.hash(state),
+            // 0.0 and -0.0 are equal so they need to hash the same
+            Primitive::Number(value) => (if *value == 0.0 { 0.0f64 } else { *value }).to_bits().hash(state),
+            Primitive::Bool(value) => value.hash(state),
+            Primitive::TypePrimitive(value) => value.get_name().hash(state),
+            // there's only one void, so the discriminant is all there is to hash
+            Primitive::Void => {}
+            // unhashable (see is_hashable), these are rejected before they're used as a key
+            Primitive::Dictionary(_) | Primitive::List(_) | Primitive::Function(_) | Primitive::NativeFunction(_) => {}
+        }
+    }
+}
+
+impl Display for Primitive {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            Type::String => write!(f, "str"),
-            Type::Number => write!(f, "num"),
-            Type::Bool => write!(f, "bool"),
-            Type::Dictionary => write!(f, "dict"),
-            Type::List => write!(f, "list"),
-            Type::Type => write!(f, "type"),
-            Type::Void => write!(f, "void"),
+            Primitive::String(value) => write!(f, "{}", value),
+            Primitive::Number(value) => write!(f, "{}", value),
+            Primitive::Bool(value) => write!(f, "{}", value),
+            Primitive::Dictionary(_) | Primitive::List(_) => self.fmt_visiting(f, &mut HashSet::new()),
+            Primitive::TypePrimitive(value) => write!(f, "{}", value),
+            Primitive::Function(value) => write!(f, "<function {}>", value.get_name()),
+            Primitive::NativeFunction(value) => write!(f, "<builtin function {}>", value.get_name()),
+            Primitive::Void => write!(f, "void"),
         }
     }
-}
\ No newline at end of file
+}
+
+// the same as display except strings are quoted, so they can be told apart from other values
+impl Debug for Primitive {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Primitive::String(value) => write!(f, "{:?}", value),
+            value => Display::fmt(value, f),
+        }
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+use crate::errorsystem::error_type::ErrorType;
+use crate::errorsystem::GlassError;
+use crate::lang::interpreter::primitive::Primitive;
+
+pub type NativeResult = Result<Primitive, GlassError>;
+
+// how many arguments a native function (or method) accepts, checked before it's called
+#[derive(Copy, Clone)]
+pub enum Arity {
+    Exact(usize),
+    AtLeast(usize),
+    Between(usize, usize),
+}
+
+impl Arity {
+    pub fn accepts(&self, count: usize) -> bool {
+        match self {
+            Arity::Exact(arity) => count == *arity,
+            Arity::AtLeast(min) => count >= *min,
+            Arity::Between(min, max) => count >= *min && count <= *max,
+        }
+    }
+
+    // the error has no position, the caller knows where the call is
+    pub fn check(&self, name: &str, count: usize) -> Result<(), GlassError> {
+        if self.accepts(count) {
+            return Ok(());
+        }
+
+        // the accepted count closest to the one given
+        let expected = match self {
+            Arity::Exact(arity) => *arity,
+            Arity::AtLeast(min) => *min,
+            Arity::Between(min, max) => if count < *min { *min } else { *max },
+        };
+
+        let error = GlassError::new(ErrorType::UnexpectedArgCount(expected, count), None);
+
+        Err(match self {
+            Arity::Exact(_) => error,
+            _ => error.with_note(format!("'{}' takes {} args", name, self)),
+        })
+    }
+}
+
+impl Display for Arity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Arity::Exact(arity) => write!(f, "{}", arity),
+            Arity::AtLeast(min) => write!(f, "at least {}", min),
+            Arity::Between(min, max) => write!(f, "{} to {}", min, max),
+        }
+    }
+}
+
+// a function implemented in rust, such as the builtins or anything registered by a host program.
+// errors returned by the closure without a position are reported at the call
+pub struct NativeFunction {
+    name: String,
+    arity: Arity,
+    function: Box<dyn Fn(Vec<Primitive>) -> NativeResult>,
+}
+
+impl NativeFunction {
+    pub fn new<F: Fn(Vec<Primitive>) -> NativeResult + 'static>(name: &str, arity: Arity, function: F) -> Self {
+        Self { name: name.to_string(), arity, function: Box::new(function) }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_arity(&self) -> Arity {
+        self.arity
+    }
+
+    pub fn call(&self, args: Vec<Primitive>) -> NativeResult {
+        (self.function)(args)
+    }
+}
@@ -0,0 +1,68 @@
+use std::cell::RefCell;
+use std::mem;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use indexmap::IndexMap;
+
+use crate::lang::interpreter::primitive::Primitive;
+
+// the contents of a list or dictionary, which is shared between every reference to it. it derefs to
+// the RefCell holding the contents so it's used like an Rc<RefCell<T>>
+pub struct Shared<T: Contents>(RefCell<T>);
+
+pub type List = Shared<Vec<Primitive>>;
+pub type Dictionary = Shared<IndexMap<Primitive, Primitive>>;
+
+pub trait Contents {
+    // moves the primitives out, leaving the container empty
+    fn take_items(&mut self) -> Vec<Primitive>;
+}
+
+impl Contents for Vec<Primitive> {
+    fn take_items(&mut self) -> Vec<Primitive> {
+        mem::take(self)
+    }
+}
+
+// keys are always hashable, so they never hold other containers
+impl Contents for IndexMap<Primitive, Primitive> {
+    fn take_items(&mut self) -> Vec<Primitive> {
+        mem::take(self).into_values().collect()
+    }
+}
+
+impl<T: Contents> Shared<T> {
+    pub fn new(contents: T) -> Rc<Self> {
+        Rc::new(Shared(RefCell::new(contents)))
+    }
+}
+
+impl<T: Contents> Deref for Shared<T> {
+    type Target = RefCell<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+// dropping a container drops its items, which would recurse once per level of nesting and overflow the
+// native stack for deeply nested ones (`c = [c]` in a loop). instead the items of every container
+// that's about to be dropped are moved into one list and unnested there, so each is dropped empty
+impl<T: Contents> Drop for Shared<T> {
+    fn drop(&mut self) {
+        let mut pending = self.0.get_mut().take_items();
+
+        while let Some(mut item) = pending.pop() {
+            match &mut item {
+                Primitive::List(list) => if let Some(list) = Rc::get_mut(list) {
+                    pending.extend(list.0.get_mut().take_items());
+                },
+                Primitive::Dictionary(entries) => if let Some(entries) = Rc::get_mut(entries) {
+                    pending.extend(entries.0.get_mut().take_items());
+                },
+                _ => {}
+            }
+        }
+    }
+}
@@ -1,21 +1,61 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::mem;
 use std::rc::Rc;
 
 use crate::lang::interpreter::primitive::Primitive;
 
-pub struct Scope<'a> {
-    parent: Option<Rc<RefCell<Scope<'a>>>>,
+pub struct Scope {
+    parent: Option<Rc<RefCell<Scope>>>,
     variables: HashMap<String, Rc<RefCell<Primitive>>>,
-    name: &'a str,
 }
 
-impl<'a> Scope<'a> {
-    pub fn new(parent: Option<Rc<RefCell<Scope<'a>>>>, name: &'a str) -> Self {
+impl Scope {
+    pub fn new(parent: Option<Rc<RefCell<Scope>>>) -> Self {
         Scope {
             parent,
             variables: HashMap::new(),
-            name,
         }
     }
-}
\ No newline at end of file
+
+    // looks the variable up in this scope first and then walks up the parent chain
+    pub fn get(&self, name: &str) -> Option<Rc<RefCell<Primitive>>> {
+        match self.variables.get(name) {
+            Some(value) => Some(Rc::clone(value)),
+            None => self.parent.as_ref().and_then(|parent| parent.borrow().get(name)),
+        }
+    }
+
+    // every name visible from this scope, used to suggest a name when one is misspelled
+    pub fn get_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.variables.keys().cloned().collect();
+
+        if let Some(parent) = &self.parent {
+            names.extend(parent.borrow().get_names());
+        }
+
+        names
+    }
+
+    // defines the variable in this scope, shadowing any variable with the same name in a parent. plain
+    // assignments, loop variables and catch variables all declare, so a function's variables never
+    // overwrite a global (or builtin) that happens to share their name
+    pub fn declare(&mut self, name: &str, value: Primitive) {
+        self.variables.insert(name.to_string(), Rc::new(RefCell::new(value)));
+    }
+
+    // removes every variable from this scope, they're returned so that they can be dropped after the
+    // scope is no longer borrowed
+    pub fn clear(&mut self) -> HashMap<String, Rc<RefCell<Primitive>>> {
+        mem::take(&mut self.variables)
+    }
+
+    // updates the closest scope that already defines the variable, otherwise declares it here. only
+    // compound assignment writes through to an enclosing scope, e.g. a counter closure's `count += 1`
+    pub fn assign(&mut self, name: &str, value: Primitive) {
+        match self.get(name) {
+            Some(variable) => *variable.borrow_mut() = value,
+            None => self.declare(name, value),
+        }
+    }
+}
@@ -27,25 +27,36 @@ lazy_static! {
         m.insert("==", TokenType::EqualEqual);
         m.insert("**=", TokenType::PowEquals);
         m.insert("void", TokenType::Void);
-        m.insert("print", TokenType::Print);
-        m.insert("println", TokenType::Println);
         m.insert("true", TokenType::True);
         m.insert("false", TokenType::False);
         m.insert("func", TokenType::Func);
         m.insert("end", TokenType::End);
+        m.insert("return", TokenType::Return);
         m.insert("list", TokenType::List);
         m.insert("dict", TokenType::Dict);
         m.insert("function", TokenType::Function);
         m.insert("in", TokenType::In);
         m.insert("if", TokenType::If);
+        m.insert("else", TokenType::Else);
+        m.insert("for", TokenType::For);
+        m.insert("to", TokenType::To);
+        m.insert("step", TokenType::Step);
+        m.insert("while", TokenType::While);
+        m.insert("break", TokenType::Break);
+        m.insert("continue", TokenType::Continue);
+        m.insert("delete", TokenType::Delete);
+        m.insert("try", TokenType::Try);
+        m.insert("catch", TokenType::Catch);
+        m.insert("finally", TokenType::Finally);
+        m.insert("throw", TokenType::Throw);
         m.insert("is", TokenType::Is);
         m.insert("bool", TokenType::Bool);
         m.insert("num", TokenType::Num);
         m.insert("!=", TokenType::NotEqual);
+        m.insert("and", TokenType::And);
         m.insert("or", TokenType::Or);
         m.insert("not", TokenType::Not);
         m.insert("str", TokenType::Str);
-        m.insert("typeof", TokenType::Typeof);
         m
     };
 }
@@ -63,6 +74,8 @@ lazy_static! {
         m.insert('\'', '\'');
         m.insert('"', '"');
         m.insert('\\', '\\');
+        m.insert('{', '{');
+        m.insert('}', '}');
         m
     };
 }
@@ -75,7 +88,7 @@ lazy_static! {
         m.insert(')', (TokenType::Rparen, Some(false)));
         m.insert('[', (TokenType::Lbracket, Some(true)));
         m.insert(']', (TokenType::Rbracket, Some(false)));
-        m.insert('{', (TokenType::Lbracket, Some(true)));
+        m.insert('{', (TokenType::Lbrace, Some(true)));
         m.insert('}', (TokenType::Rbrace, Some(false)));
         m.insert(',', (TokenType::Comma, None));
         m.insert(':', (TokenType::Colon, None));
@@ -88,6 +101,11 @@ pub fn get_token<'a, S: Into<String>>(value: S) -> Option<&'a TokenType> {
     TOKEN_MAP.get(&*value.into())
 }
 
+// the words which can't be used as identifiers
+pub fn get_keywords() -> impl Iterator<Item = &'static str> {
+    TOKEN_MAP.keys().copied().filter(|key| key.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
 pub fn get_esc<'a>(value: char) -> Option<&'a char> {
     ESC_MAP.get(&value)
 }
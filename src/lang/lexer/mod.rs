@@ -1,17 +1,18 @@
-use crate::dispatch_error;
 use crate::errorsystem::error_type::ErrorType;
+use crate::errorsystem::raise_error;
+use crate::errorsystem::GlassError;
 use crate::lang::lexer::position::Position;
 use crate::lang::lexer::token::Token;
 use crate::lang::lexer::token::token_type::TokenType;
 
 pub mod token;
-mod char_maps;
+pub mod char_maps;
 pub mod position;
 
 pub struct Lexer<'a> {
     value: Vec<char>,
     index: usize,
-    position: Position<'a>,
+    position: Position,
     current: char,
     filename: &'a str,
     wrap_count: usize, // usize instead of bool because of nested brackets, parentheses, or braces
@@ -19,12 +20,8 @@ pub struct Lexer<'a> {
 
 impl<'a> Lexer<'a> {
     pub fn new(filename: &'a str, source: &'a str) -> Self {
-        if source.len() == 0 {
-            dispatch_error!(ErrorType::EmptyFile(filename));
-        }
-
         let chars: Vec<char> = source.chars().collect();
-        let first = if chars.len() <= 0 { '\0' } else { *chars.get(0).unwrap() };
+        let first = *chars.first().unwrap_or(&'\0');
 
         Self {
             filename,
@@ -36,43 +33,59 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    pub fn lex(&mut self) -> Vec<Token<'a>> {
+    pub fn lex(&mut self) -> Result<Vec<Token>, GlassError> {
+        if self.value.is_empty() {
+            raise_error!(ErrorType::EmptyFile(self.filename.to_string()));
+        }
+
         let mut tokens = Vec::new();
 
         while !self.is_done() { // we have more tokens to consume
-            if self.is_newline() {
-                if self.get_last_token(&tokens) == TokenType::Newline || self.wrap_count > 0 { self.advance(); } else { tokens.push(self.consume_newline()); }; // implicit line joining
-            } else if self.current.is_whitespace() {
-                self.advance();
-            } else if self.current.is_ascii_digit() {
-                tokens.push(self.consume_number());
-            } else if self.is_operator() {
-                tokens.push(self.consume_operator());
-            } else if self.single_check() {
-                tokens.push(self.consume_single());
-            } else if self.is_quote() {
-                tokens.push(self.consume_string());
-            } else if self.is_comment() {
-                self.consume_comment();
-            } else {
-                tokens.push(self.consume_identifier());
-            }
+            self.lex_next(&mut tokens)?;
+        }
+
+        // every statement is newline terminated, including the last one in the file
+        if tokens.is_empty() || self.get_last_token(&tokens) != TokenType::Newline {
+            tokens.push(Token::new(TokenType::Newline, None, self.position.clone()));
         }
 
-        tokens
+        Ok(tokens)
     }
 
     pub fn get_filename(&self) -> &'a str {
         self.filename
     }
 
-    fn consume_newline(&mut self) -> Token<'a> {
+    fn lex_next(&mut self, tokens: &mut Vec<Token>) -> Result<(), GlassError> {
+        if self.is_newline() {
+            if self.get_last_token(tokens) == TokenType::Newline || self.wrap_count > 0 { self.advance(); } else { tokens.push(self.consume_newline()); }; // implicit line joining
+        } else if self.current.is_whitespace() {
+            self.advance();
+        } else if self.current.is_ascii_digit() {
+            tokens.push(self.consume_number()?);
+        } else if self.is_operator() {
+            tokens.push(self.consume_operator()?);
+        } else if self.single_check() {
+            tokens.push(self.consume_single()?);
+        } else if self.is_quote() {
+            self.consume_string(tokens)?;
+        } else if self.is_comment() {
+            self.consume_comment();
+        } else {
+            tokens.push(self.consume_identifier()?);
+        }
+
+        Ok(())
+    }
+
+    fn consume_newline(&mut self) -> Token {
+        let start = self.position.clone();
         self.advance();
-        Token::new(TokenType::Newline, None, self.position)
+        Token::new(TokenType::Newline, None, start)
     }
 
-    fn consume_number(&mut self) -> Token<'a> {
-        let start = self.position;
+    fn consume_number(&mut self) -> Result<Token, GlassError> {
+        let start = self.position.clone();
         let mut buffer = String::new();
         let mut dec = false;
 
@@ -80,7 +93,7 @@ impl<'a> Lexer<'a> {
             if self.current == '.' {
                 if dec {
                     // already had a decimal in the number
-                    dispatch_error!(ErrorType::DoubleDecimal, self.position);
+                    raise_error!(ErrorType::DoubleDecimal, self.position.clone());
                 }
                 dec = true;
             }
@@ -91,14 +104,14 @@ impl<'a> Lexer<'a> {
 
         if buffer.ends_with('.') {
             // number can't end with a decimal
-            dispatch_error!(ErrorType::DecimalEnding, self.position);
+            raise_error!(ErrorType::DecimalEnding, self.position.clone());
         }
 
-        Token::new(TokenType::Number, Some(buffer), start)
+        Ok(Token::new(TokenType::Number, Some(buffer), start.span_to(&self.position)))
     }
 
-    fn consume_operator(&mut self) -> Token<'a> {
-        let start = self.position;
+    fn consume_operator(&mut self) -> Result<Token, GlassError> {
+        let start = self.position.clone();
         let mut buffer = String::new();
 
         while !self.is_done() && self.is_operator() {
@@ -107,31 +120,47 @@ impl<'a> Lexer<'a> {
         }
 
         match char_maps::get_token(&buffer) {
-            Some(operator) => Token::new(*operator, None, start),
+            Some(operator) => Ok(Token::new(*operator, None, start.span_to(&self.position))),
             _ => {
-                dispatch_error!(ErrorType::InvalidOperator(buffer), start);
+                raise_error!(ErrorType::InvalidOperator(buffer), start.span_to(&self.position));
             }
         }
     }
 
-    // todo - test strings and all that fun stuff!
-    fn consume_string(&mut self) -> Token<'a> {
-        let start = self.position;
+    // a plain string is a single String token. a string with `{expr}` in it is lexed as InterpolationStart,
+    // the literal parts as String tokens with each expression's tokens between braces in between them,
+    // then InterpolationEnd, e.g. "a{x}b" -> InterpolationStart String(a) { x } String(b) InterpolationEnd
+    fn consume_string(&mut self, tokens: &mut Vec<Token>) -> Result<(), GlassError> {
+        let start = self.position.clone();
+        let first = tokens.len();
         self.advance();
         let mut buffer = String::new();
+        let mut part_start = self.position.clone();
+        let mut interpolated = false;
         let mut esc = false;
 
-        while !self.is_done() && !(self.is_quote() && !esc) {
+        while !self.is_done() && (!self.is_quote() || esc) {
             if esc {
                 match char_maps::get_esc(self.current) {
                     Some(escaped) => buffer.push(*escaped),
                     None => {
-                        dispatch_error!(ErrorType::UnknownEscapeSequence(self.current), self.position);
+                        raise_error!(ErrorType::UnknownEscapeSequence(self.current), self.position.clone());
                     }
                 }
                 esc = false;
             } else if self.current == '\\' { // escape character
                 esc = true;
+            } else if self.current == '{' {
+                if !interpolated {
+                    tokens.push(Token::new(TokenType::InterpolationStart, None, start.clone()));
+                    interpolated = true;
+                }
+
+                tokens.push(Token::new(TokenType::String, Some(buffer), part_start.span_to(&self.position)));
+                buffer = String::new();
+                self.consume_interpolation(tokens)?;
+                part_start = self.position.clone();
+                continue;
             } else {
                 buffer.push(self.current);
             }
@@ -140,15 +169,56 @@ impl<'a> Lexer<'a> {
         }
 
         if !self.is_quote() {
-            dispatch_error!(ErrorType::UnclosedString, start);
+            let error = GlassError::new(ErrorType::UnclosedString, Some(self.position.clone()));
+            return Err(error.with_label(start, "string started here"));
+        }
+
+        if interpolated {
+            tokens.push(Token::new(TokenType::String, Some(buffer), part_start.span_to(&self.position)));
+            tokens.push(Token::new(TokenType::InterpolationEnd, None, self.position.clone()));
+            self.advance();
+
+            // the start token spans the whole string so errors about it underline all of it
+            tokens[first] = Token::new(TokenType::InterpolationStart, None, start.span_to(&self.position));
+        } else {
+            self.advance();
+            tokens.push(Token::new(TokenType::String, Some(buffer), start.span_to(&self.position)));
+        }
+
+        Ok(())
+    }
+
+    // the tokens of an `{expr}` inside a string, up to the brace closing it. the expression can span lines
+    // and contain strings and dictionaries of its own
+    fn consume_interpolation(&mut self, tokens: &mut Vec<Token>) -> Result<(), GlassError> {
+        let open = self.position.clone();
+        tokens.push(Token::new(TokenType::Lbrace, None, open.clone()));
+        self.advance();
+
+        self.wrap_count += 1;
+        let depth = self.wrap_count;
+
+        while !self.is_done() && (self.current != '}' || self.wrap_count != depth) {
+            match self.lex_next(tokens) {
+                // the quote meant to close the string opened a new one instead, so the brace is the mistake
+                Err(error) if matches!(error.get_error_type(), ErrorType::UnclosedString) => break,
+                result => result?,
+            }
         }
 
+        if self.is_done() {
+            let error = GlassError::new(ErrorType::UnclosedInterpolation, Some(open));
+            return Err(error.with_help(String::from("close it with '}', or write '\\{' for a brace in the string")));
+        }
+
+        self.wrap_count -= 1;
+        tokens.push(Token::new(TokenType::Rbrace, None, self.position.clone()));
         self.advance();
-        Token::new(TokenType::String, Some(buffer), start)
+        Ok(())
     }
 
-    fn consume_single(&mut self) -> Token<'a> {
-        let start = self.position;
+    fn consume_single(&mut self) -> Result<Token, GlassError> {
+        let start = self.position.clone();
 
         let &token_type = match char_maps::get_single(self.current) {
             Some((token_type, wrap)) => {
@@ -162,23 +232,23 @@ impl<'a> Lexer<'a> {
                 token_type
             }
             None => {
-                dispatch_error!(ErrorType::UnknownChar(self.current), start);
+                raise_error!(ErrorType::UnknownChar(self.current), start);
             }
         };
 
         self.advance();
-        Token::new(token_type, None, start)
+        Ok(Token::new(token_type, None, start))
     }
 
     fn consume_comment(&mut self) {
+        // the newline itself is left alone so it still ends the statement before the comment
         while !self.is_done() && !self.is_newline() {
             self.advance();
         }
-        self.advance();
     }
 
-    fn consume_identifier(&mut self) -> Token<'a> {
-        let start = self.position;
+    fn consume_identifier(&mut self) -> Result<Token, GlassError> {
+        let start = self.position.clone();
         let mut buffer = String::new();
 
         while !self.is_done() && self.current == '_' || self.current.is_ascii_digit() || self.current.is_ascii_alphabetic()
@@ -187,24 +257,27 @@ impl<'a> Lexer<'a> {
             self.advance();
         }
 
-        if buffer.len() < 1 {
-            dispatch_error!(ErrorType::UnknownChar(self.current), self.position);
+        if buffer.is_empty() {
+            raise_error!(ErrorType::UnknownChar(self.current), self.position.clone());
         }
 
         match char_maps::get_token(&buffer) {
-            Some(&token) => Token::new(token, None, start),
-            None => Token::new(TokenType::Identifier, Some(buffer), start)
+            Some(&token) => Ok(Token::new(token, None, start.span_to(&self.position))),
+            None => Ok(Token::new(TokenType::Identifier, Some(buffer), start.span_to(&self.position)))
         }
     }
 
     fn advance(&mut self) {
+        // the row changes once we move past a line break, ';' separates statements but not lines
+        let newline = self.current == '\n';
+
         self.index += 1;
         self.current = if self.is_done() { '\0' } else { self.value[self.index] };
-        self.position.advance(self.is_newline());
+        self.position.advance(newline);
     }
 
-    fn get_last_token(&self, tokens: &Vec<Token>) -> TokenType {
-        if tokens.len() > 0 { tokens.last().unwrap().get_type() } else { TokenType::Newline }
+    fn get_last_token(&self, tokens: &[Token]) -> TokenType {
+        tokens.last().map_or(TokenType::Newline, |token| token.get_type())
     }
 
     fn is_done(&self) -> bool {
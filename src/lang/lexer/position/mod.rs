@@ -1,56 +1,91 @@
 use std::fmt::Display;
+use std::rc::Rc;
 
-use crate::dispatch_error;
-use crate::errorsystem::error_type::ErrorType;
-
-#[derive(Copy, Clone)]
-pub struct Position<'a> {
-    source: &'a str,
-    filename: &'a str,
-    absolute_index: i32,
+// source and filename are reference counted so positions (and the tokens and nodes holding them)
+// can outlive the lexer, e.g. a function defined on one REPL line and called on the next
+#[derive(Clone)]
+pub struct Position {
+    source: Rc<str>,
+    filename: Rc<str>,
+    absolute_index: usize,
+    end: usize, // exclusive, positions span from absolute_index up to end
     column: usize,
     row: usize,
 }
 
-impl<'a> Position<'a> {
-    pub fn new(filename: &'a str, source: &'a str) -> Self {
-        Position { filename, source, absolute_index: 0, column: 1, row: 1 }
+impl Position {
+    pub fn new(filename: &str, source: &str) -> Self {
+        Position { filename: Rc::from(filename), source: Rc::from(source), absolute_index: 0, end: 1, column: 1, row: 1 }
     }
 
     pub fn advance(&mut self, newline: bool) -> &Position {
         self.column = if newline { 1 } else { self.column + 1 };
         self.row = if newline { self.row + 1 } else { self.row };
         self.absolute_index += 1;
+        self.end = self.absolute_index + 1;
 
         self
     }
 
-    fn get_line(&self, line: usize) -> (&str, usize) {
-        let orig = match self.source.lines().nth(line) {
-            Some(line) => line,
-            None => {
-                dispatch_error!(ErrorType::GenericError("converting position to string"));
+    // the span from this position up to (but not including) the given one, always at least one character
+    pub fn span_to(&self, end: &Position) -> Position {
+        Position { end: end.absolute_index.max(self.absolute_index + 1), ..self.clone() }
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.absolute_index
+    }
+
+    // the number of characters spanned
+    pub fn get_length(&self) -> usize {
+        self.end - self.absolute_index
+    }
+
+    pub fn get_filename(&self) -> &str {
+        &self.filename
+    }
+
+    pub fn get_row(&self) -> usize {
+        self.row
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    // the row and (exclusive) column the span ends at. a span can reach past the last character (an
+    // error at the end of the file), which counts as columns on the last line
+    pub fn get_end(&self) -> (usize, usize) {
+        let (mut row, mut column) = (self.row, self.column);
+        let mut spanned = 0;
+
+        for c in self.source.chars().skip(self.absolute_index).take(self.get_length()) {
+            if c == '\n' {
+                row += 1;
+                column = 1;
+            } else {
+                column += 1;
             }
-        };
 
-        let result = orig.trim_start();
-        
-        (result, orig.len() - result.len())
+            spanned += 1;
+        }
+
+        (row, column + self.get_length() - spanned)
+    }
+
+    // the line of source the position starts on, positions at the very end of a file ending in a newline
+    // are on an empty line
+    pub fn get_line(&self) -> Option<&str> {
+        match self.source.lines().nth(self.row - 1) {
+            Some(line) => Some(line),
+            None if self.row == self.source.lines().count() + 1 => Some(""),
+            None => None,
+        }
     }
 }
 
-impl Display for Position<'_> {
+impl Display for Position {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let line = self.row - 1;
-        let (str_line, offset) = self.get_line(line);
-        let col = self.column - 1 - offset;
-
-        write!(
-            f,
-            "\n\n\t\t{}\n\t\t{}^\n\t[{}(Ln:{} Col:{})]",
-            str_line,
-            if col > 0 { " ".repeat(col - 1) } else { String::new() },
-            self.filename, self.row, self.column
-        )
-    }
-}
\ No newline at end of file
+        write!(f, "{}(Ln:{} Col:{})", self.filename, self.row, self.column)
+    }
+}
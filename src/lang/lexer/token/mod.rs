@@ -5,41 +5,39 @@ use crate::lang::lexer::token::token_type::TokenType;
 
 pub mod token_type;
 
-pub struct Token<'a> {
+#[derive(Clone)]
+pub struct Token {
     token_type: TokenType,
     value: String,
-    pos: Position<'a>,
+    pos: Position,
 }
 
-impl<'a> Token<'a> {
-    pub fn new(token_type: TokenType, value: Option<String>, pos: Position<'a>) -> Self {
+impl Token {
+    pub fn new(token_type: TokenType, value: Option<String>, pos: Position) -> Self {
         Token {
             token_type,
-            value: match value {
-                Some(value) => value,
-                None => String::new()
-            },
+            value: value.unwrap_or_default(),
             pos,
         }
     }
 
     pub fn get_type(&self) -> TokenType {
-        return self.token_type;
+        self.token_type
     }
 
-    pub fn take_pos(&self) -> Position<'a> {
-        return self.pos;
+    pub fn take_pos(&self) -> Position {
+        self.pos.clone()
     }
 
     // todo: don't clone, maybe use Rc?
     pub fn get_value(&self) -> String {
-        return self.value.clone();
+        self.value.clone()
     }
 }
 
-impl Display for Token<'_> {
+impl Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let token_type: &str = self.token_type.into();
         write!(f, "{}{}", token_type, if self.value.is_empty() { String::new() } else { format!("({})", self.value) })
     }
-}
\ No newline at end of file
+}
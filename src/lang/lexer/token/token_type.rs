@@ -15,6 +15,7 @@ pub enum TokenType {
     ModEquals,
     PowEquals,
     Not,
+    And,
     Or,
     Is,
     GreaterThan,
@@ -37,25 +38,82 @@ pub enum TokenType {
     Num,
     Str,
     String,
+    InterpolationStart,
+    InterpolationEnd,
     Func,
     Function,
     Bool,
     True,
     False,
-    Print,
-    Println,
     Void,
     List,
     Dict,
     If,
+    Else,
+    For,
+    To,
+    Step,
+    While,
+    Break,
+    Continue,
+    Delete,
     In,
     End,
+    Return,
     Newline,
     Pow,
-    Typeof,
     Comma,
     Colon,
     Try,
     Catch,
     Finally,
-}
\ No newline at end of file
+    Throw,
+}
+impl TokenType {
+    pub fn is_assignment(&self) -> bool {
+        *self == TokenType::Equal || self.get_compound_op().is_some()
+    }
+
+    // the operator a compound assignment applies before assigning, e.g. PlusEquals -> Plus
+    pub fn get_compound_op(&self) -> Option<TokenType> {
+        match self {
+            TokenType::PlusEquals => Some(TokenType::Plus),
+            TokenType::MinusEquals => Some(TokenType::Minus),
+            TokenType::TimesEquals => Some(TokenType::Times),
+            TokenType::DivideEquals => Some(TokenType::Divide),
+            TokenType::ModEquals => Some(TokenType::Mod),
+            TokenType::PowEquals => Some(TokenType::Pow),
+            _ => None,
+        }
+    }
+
+    // how the operator is written in source, for error messages. other token types fall back to their name
+    pub fn get_symbol(&self) -> &'static str {
+        match self {
+            TokenType::Plus => "+",
+            TokenType::Minus => "-",
+            TokenType::Times => "*",
+            TokenType::Divide => "/",
+            TokenType::Mod => "%",
+            TokenType::Pow => "**",
+            TokenType::PlusEquals => "+=",
+            TokenType::MinusEquals => "-=",
+            TokenType::TimesEquals => "*=",
+            TokenType::DivideEquals => "/=",
+            TokenType::ModEquals => "%=",
+            TokenType::PowEquals => "**=",
+            TokenType::GreaterThan => ">",
+            TokenType::GreaterThanEqual => ">=",
+            TokenType::LessThan => "<",
+            TokenType::LessThanEqual => "<=",
+            TokenType::EqualEqual => "==",
+            TokenType::NotEqual => "!=",
+            TokenType::Not => "not",
+            TokenType::And => "and",
+            TokenType::Or => "or",
+            TokenType::Is => "is",
+            TokenType::In => "in",
+            token_type => token_type.into(),
+        }
+    }
+}
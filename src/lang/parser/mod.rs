@@ -1,21 +1,112 @@
-use crate::{dispatch_error, Lexer};
+use std::mem;
+use std::rc::Rc;
+
 use crate::errorsystem::error_type::ErrorType;
-use crate::lang::lexer::position::Position;
+use crate::errorsystem::raise_error;
+use crate::errorsystem::{GlassError, suggestion};
+use crate::lang::interpreter::primitive::Type;
+use crate::lang::interpreter::{RED_ZONE, STACK_SIZE};
+use crate::lang::lexer::char_maps;
 use crate::lang::lexer::token::Token;
 use crate::lang::lexer::token::token_type::TokenType;
+use crate::lang::parser::node::attribute_node::AttributeNode;
 use crate::lang::parser::node::bin_op_node::BinOpNode;
+use crate::lang::parser::node::block_node::BlockNode;
 use crate::lang::parser::node::bool_node::BoolNode;
+use crate::lang::parser::node::break_node::BreakNode;
+use crate::lang::parser::node::call_node::CallNode;
+use crate::lang::parser::node::continue_node::ContinueNode;
+use crate::lang::parser::node::dict_node::DictNode;
+use crate::lang::parser::node::for_in_node::ForInNode;
+use crate::lang::parser::node::for_node::ForNode;
+use crate::lang::parser::node::func_def_node::FuncDefNode;
+use crate::lang::parser::node::function_node::FunctionNode;
+use crate::lang::parser::node::if_node::IfNode;
+use crate::lang::parser::node::index_node::IndexNode;
+use crate::lang::parser::node::interpolation_node::InterpolationNode;
+use crate::lang::parser::node::list_node::ListNode;
+use crate::lang::parser::node::method_call_node::MethodCallNode;
 use crate::lang::parser::node::Node;
 use crate::lang::parser::node::number_node::NumberNode;
+use crate::lang::parser::node::return_node::ReturnNode;
+use crate::lang::parser::node::slice_node::SliceNode;
 use crate::lang::parser::node::string_node::StringNode;
+use crate::lang::parser::node::throw_node::ThrowNode;
+use crate::lang::parser::node::try_node::TryNode;
+use crate::lang::parser::node::type_node::TypeNode;
 use crate::lang::parser::node::unary_node::UnaryNode;
+use crate::lang::parser::node::var_access_node::VarAccessNode;
 use crate::lang::parser::node::void_node::VoidNode;
+use crate::lang::parser::node::while_node::WhileNode;
 
 pub mod node;
 
-pub struct Parser<'a> {
-    tokens: Vec<Token<'a>>,
+pub type ParseResult = Result<Box<dyn Node>, GlassError>;
+
+pub const DEFAULT_MAX_ERRORS: usize = 20;
+
+// how deeply expressions and statements can be nested, e.g. `((((1))))` or an `if` inside of a `while`.
+// the parser is recursive, so without a limit deeply nested source would overflow the native stack
+pub const MAX_NESTING: usize = 200;
+
+pub struct Parser {
+    tokens: Vec<Token>,
     index: usize,
+    errors: Vec<GlassError>,
+    max_errors: usize, // parsing stops once this many errors have been reported
+    function_depth: usize, // how many function bodies we're in, return is only valid inside of one
+    loop_depth: usize, // how many loop bodies we're in (within the current function), same for break and continue
+    nesting: usize, // how many expressions and statements we're inside of, limited to MAX_NESTING
+}
+
+#[derive(Copy, Clone)]
+enum Associativity {
+    Left,
+    Right,
+}
+
+// the binary operators with their precedence, a higher one binds tighter. the prefix operators sit in
+// between: `not` binds looser than the comparisons, unary `-` and `+` looser than `**` (see parse_prefix).
+// comparisons don't chain, `a < b < c` is `(a < b) < c`
+//
+//   1  or                                    left
+//   2  and                                   left
+//   3  not                                   prefix
+//   4  ==  !=                                left
+//   5  <  <=  >  >=  in  is  is not          left
+//   6  +  -                                  left
+//   7  *  /  %                               left
+//   8  -  +                                  prefix
+//   9  **                                    right
+//
+// followed by calls, method calls, indexing and slicing, which bind tightest of all
+const BINARY_OPERATORS: &[(TokenType, u8, Associativity)] = &[
+    (TokenType::Or, 1, Associativity::Left),
+    (TokenType::And, 2, Associativity::Left),
+    (TokenType::EqualEqual, 4, Associativity::Left),
+    (TokenType::NotEqual, 4, Associativity::Left),
+    (TokenType::LessThan, 5, Associativity::Left),
+    (TokenType::LessThanEqual, 5, Associativity::Left),
+    (TokenType::GreaterThan, 5, Associativity::Left),
+    (TokenType::GreaterThanEqual, 5, Associativity::Left),
+    (TokenType::In, 5, Associativity::Left),
+    (TokenType::Is, 5, Associativity::Left),
+    (TokenType::Plus, 6, Associativity::Left),
+    (TokenType::Minus, 6, Associativity::Left),
+    (TokenType::Times, 7, Associativity::Left),
+    (TokenType::Divide, 7, Associativity::Left),
+    (TokenType::Mod, 7, Associativity::Left),
+    (TokenType::Pow, 9, Associativity::Right),
+];
+
+const LOWEST_PRECEDENCE: u8 = 1;
+const NOT_PRECEDENCE: u8 = 3;
+const UNARY_PRECEDENCE: u8 = 8;
+
+fn get_binary_operator(token_type: TokenType) -> Option<(u8, Associativity)> {
+    BINARY_OPERATORS.iter()
+        .find(|(operator, _, _)| *operator == token_type)
+        .map(|&(_, precedence, associativity)| (precedence, associativity))
 }
 
 macro_rules! token_matches {
@@ -27,143 +118,713 @@ macro_rules! token_matches {
     };
 }
 
-impl<'a> Parser<'a> {
-    pub fn new(tokens: Vec<Token<'a>>) -> Parser<'a> {
+impl Parser {
+    pub fn new(tokens: Vec<Token>, max_errors: usize) -> Parser {
         Parser {
             tokens,
             index: 0,
+            errors: Vec::new(),
+            max_errors: max_errors.max(1),
+            function_depth: 0,
+            loop_depth: 0,
+            nesting: 0,
         }
     }
 
-    pub fn parse(&mut self) -> Box<dyn Node> {
-        self.parse_expression()
-    }
+    // every syntax error in the source (up to max_errors) is returned, sorted by where it occurred
+    pub fn parse(&mut self) -> Result<Box<dyn Node>, Vec<GlassError>> {
+        match self.parse_block(&[]) {
+            Ok(block) if self.errors.is_empty() => return Ok(block),
+            Ok(_) => {}
+            Err(error) => self.report(error),
+        }
 
-    fn parse_expression(&mut self) -> Box<dyn Node> {
-        self.parse_equality()
+        let mut errors = mem::take(&mut self.errors);
+        errors.sort_by_key(|error| error.get_position().map_or(usize::MAX, |pos| pos.get_index()));
+        Err(errors)
     }
 
-    fn parse_equality(&mut self) -> Box<dyn Node> {
-        let mut left = self.parse_comparison();
+    // parses newline separated statements until one of the terminators or the end of the file is reached,
+    // the terminator itself is left for the caller to consume
+    fn parse_block(&mut self, terminators: &[TokenType]) -> ParseResult {
+        let token = self.peek()?.clone();
+        let mut statements = Vec::new();
 
-        if self.at_end() {
-            return left;
+        self.skip_newlines();
+
+        while !self.at_end() && !terminators.contains(&self.peek()?.get_type()) {
+            match self.parse_line(terminators) {
+                Ok(statement) => statements.push(statement),
+                // the rest of the line is skipped and parsing picks back up on the next one, so a single
+                // run reports every syntax error instead of just the first
+                Err(error) => {
+                    self.report(error);
+                    self.synchronize(terminators);
+                }
+            }
+
+            self.skip_newlines();
         }
 
-        while token_matches!(self.peek(), TokenType::EqualEqual, TokenType::NotEqual) {
-            let op = self.next_token().get_type();
-            let right = self.parse_comparison();
-            left = Box::new(BinOpNode::new(op, left, right));
+        Ok(Box::new(BlockNode::new(statements, token)))
+    }
+
+    fn parse_line(&mut self, terminators: &[TokenType]) -> ParseResult {
+        let start = self.index;
+
+        // the value of a loop directly in a block is thrown away, so it doesn't need to be collected
+        let statement = if self.peek()?.get_type() == TokenType::For {
+            self.parse_for(false)?
+        } else {
+            self.parse_statement()?
         };
 
-        left
+        // anything left on the line after a complete statement is an error
+        if !self.at_end() && !terminators.contains(&self.peek()?.get_type()) {
+            // a lone word followed by more of the statement is most likely a misspelled keyword,
+            // e.g. `fucn add(a, b) => a + b`
+            if self.index == start + 1 && self.tokens[start].get_type() == TokenType::Identifier && self.peek()?.get_type() != TokenType::Newline {
+                return Err(self.unknown_keyword(&self.tokens[start]));
+            }
+
+            self.expect(TokenType::Newline)?;
+        }
+
+        Ok(statement)
     }
 
-    fn parse_comparison(&mut self) -> Box<dyn Node> {
-        let mut left = self.parse_term();
+    fn unknown_keyword(&self, token: &Token) -> GlassError {
+        let word = token.get_value();
+        let error = GlassError::new(ErrorType::UnknownKeyword(word.clone()), Some(token.take_pos()));
 
-        if self.at_end() {
-            left
+        match suggestion::did_you_mean(&word, char_maps::get_keywords()) {
+            Some(keyword) => error.with_help(format!("did you mean '{}'?", keyword)),
+            None => error,
+        }
+    }
+
+    fn report(&mut self, error: GlassError) {
+        if self.errors.len() < self.max_errors {
+            self.errors.push(error);
+        }
+
+        // once the cap is reached there's no point in parsing the rest of the tokens
+        if self.errors.len() >= self.max_errors {
+            self.index = self.tokens.len();
+        }
+    }
+
+    // skips the rest of the line the error occurred on, stopping early at a terminator so that the
+    // construct the block belongs to can still be closed
+    fn synchronize(&mut self, terminators: &[TokenType]) {
+        // the error may have been the newline itself, in which case we're already on the next line
+        if self.index > 0 && self.tokens[self.index - 1].get_type() == TokenType::Newline {
+            return;
+        }
+
+        while !self.at_end() {
+            let token_type = self.tokens[self.index].get_type();
+
+            if terminators.contains(&token_type) {
+                break;
+            }
+
+            self.index += 1;
+
+            if token_type == TokenType::Newline {
+                break;
+            }
+        }
+    }
+
+    // bodies are either a single statement on the same line as the `=>` or a block on the lines after it,
+    // in which case the caller is responsible for consuming the terminator (returned bool is true)
+    fn parse_body(&mut self, terminators: &[TokenType]) -> Result<(Box<dyn Node>, bool), GlassError> {
+        if !self.at_end() && self.peek()?.get_type() == TokenType::Newline {
+            Ok((self.parse_block(terminators)?, true))
         } else {
-            while token_matches!(self.peek(), TokenType::EqualEqual, TokenType::NotEqual, TokenType::LessThan, TokenType::LessThanEqual, TokenType::GreaterThan, TokenType::GreaterThanEqual) {
-                let op = self.next_token().get_type();
-                let right = self.parse_term();
-                left = Box::new(BinOpNode::new(op, left, right));
+            Ok((self.parse_statement()?, false))
+        }
+    }
+
+    // every nested statement and expression passes through parse_statement or parse_prefix, so they're
+    // where the nesting is counted. the limit keeps the tree shallow enough for the interpreter, and
+    // growing the stack keeps the parser itself from overflowing on threads with small stacks
+    fn nested(&mut self, parse: fn(&mut Self) -> ParseResult) -> ParseResult {
+        if self.nesting >= MAX_NESTING {
+            raise_error!(ErrorType::NestingLimit(MAX_NESTING), self.peek()?.take_pos());
+        }
+
+        self.nesting += 1;
+        let result = stacker::maybe_grow(RED_ZONE, STACK_SIZE, || parse(self));
+        self.nesting -= 1;
+        result
+    }
+
+    fn parse_statement(&mut self) -> ParseResult {
+        self.nested(Self::parse_unnested_statement)
+    }
+
+    fn parse_unnested_statement(&mut self) -> ParseResult {
+        match self.peek()?.get_type() {
+            TokenType::Func => return self.parse_func_def(),
+            TokenType::Return => return self.parse_return(),
+            TokenType::While => return self.parse_while(),
+            TokenType::Break | TokenType::Continue => return self.parse_loop_control(),
+            TokenType::Delete => return self.parse_delete(),
+            TokenType::Try => return self.parse_try(),
+            TokenType::Throw => return self.parse_throw(),
+            _ => {}
+        }
+
+        let expr = self.parse_expression()?;
+
+        if !self.at_end() && self.peek()?.get_type().is_assignment() {
+            let op = self.next_token()?.clone();
+            let value = self.parse_expression()?;
+
+            return match expr.to_assignment(op.clone(), value) {
+                Some(assignment) => Ok(assignment),
+                None => {
+                    raise_error!(ErrorType::InvalidAssignment, op.take_pos());
+                }
             };
+        }
+
+        Ok(expr)
+    }
+
+    fn parse_func_def(&mut self) -> ParseResult {
+        let token = self.next_token()?.clone();
+        let name = self.expect(TokenType::Identifier)?;
+        let params = self.parse_params()?;
+        let body = self.parse_function_body(&token)?;
+
+        Ok(Box::new(FuncDefNode::new(token, name, params, body)))
+    }
+
+    // `function(params) => body`, the same as a definition but without a name and evaluating to the function
+    fn parse_anonymous_function(&mut self, token: Token) -> ParseResult {
+        let params = self.parse_params()?;
+        let body = self.parse_function_body(&token)?;
+
+        Ok(Box::new(FunctionNode::new(token, params, body)))
+    }
+
+    // everything from the `=>` after the parameters to the end of the function
+    fn parse_function_body(&mut self, token: &Token) -> Result<Rc<dyn Node>, GlassError> {
+        self.expect(TokenType::Lambda)?;
+
+        // loops outside of the function can't be broken out of from inside of it
+        // the counters are restored before checking the result, an error in the body is recovered from
+        // further up and the code after it must still see the depths it was written at
+        let loop_depth = mem::replace(&mut self.loop_depth, 0);
+        self.function_depth += 1;
+        let body = self.parse_body(&[TokenType::End]);
+        self.function_depth -= 1;
+        self.loop_depth = loop_depth;
+        let (body, is_block) = body?;
+
+        // a single line body is the function's result, `func double(x) => x * 2`
+        Ok(if is_block {
+            self.expect(TokenType::End)?;
+            Rc::from(body)
+        } else {
+            Rc::new(ReturnNode::new(token.clone(), Some(body)))
+        })
+    }
+
+    // the parenthesized, comma separated parameter names of a function
+    fn parse_params(&mut self) -> Result<Vec<Token>, GlassError> {
+        let mut params = Vec::new();
+        let open = self.expect(TokenType::Lparen)?;
 
-            left
+        if self.peek()?.get_type() != TokenType::Rparen {
+            params.push(self.expect(TokenType::Identifier)?);
+
+            while self.peek()?.get_type() == TokenType::Comma {
+                self.next_token()?;
+                params.push(self.expect(TokenType::Identifier)?);
+            }
         }
+
+        self.expect_closing(TokenType::Rparen, &open)?;
+        Ok(params)
     }
 
-    fn parse_term(&mut self) -> Box<dyn Node> {
-        let mut left = self.parse_factor();
+    fn parse_return(&mut self) -> ParseResult {
+        let token = self.next_token()?.clone();
 
-        if self.at_end() {
-            left
+        if self.function_depth == 0 {
+            raise_error!(ErrorType::ReturnOutsideFunction, token.take_pos());
+        }
+
+        let value = if token_matches!(self.peek()?, TokenType::Newline, TokenType::End) {
+            None
         } else {
-            while token_matches!(self.peek(), TokenType::Plus, TokenType::Minus) {
-                let op = self.next_token().get_type();
-                let right = self.parse_factor();
-                left = Box::new(BinOpNode::new(op, left, right));
-            };
+            Some(self.parse_expression()?)
+        };
+
+        Ok(Box::new(ReturnNode::new(token, value)))
+    }
+
+    // `if a => ... else if b => ... else ... end`, the whole chain shares a single end which is only
+    // required when at least one of the bodies is a block
+    fn parse_if(&mut self) -> ParseResult {
+        let mut branches = Vec::new();
+        let mut else_body = None;
+        let mut needs_end = false;
+
+        loop {
+            let token = self.expect(TokenType::If)?;
+            let condition = self.parse_expression()?;
+            self.expect(TokenType::Lambda)?;
+
+            let (body, is_block) = self.parse_body(&[TokenType::Else, TokenType::End])?;
+            needs_end |= is_block;
+            branches.push((token, condition, body));
+
+            if !self.continues_with(TokenType::Else) {
+                break;
+            }
+
+            self.next_token()?;
+
+            if self.peek()?.get_type() == TokenType::If {
+                continue;
+            }
+
+            if self.peek()?.get_type() == TokenType::Lambda {
+                self.next_token()?; // `else =>` reads naturally next to the other branches, so allow it
+            }
+
+            let (body, is_block) = self.parse_body(&[TokenType::End])?;
+            needs_end |= is_block;
+            else_body = Some(body);
+            break;
+        }
 
-            left
+        if needs_end {
+            self.skip_newlines(); // the last branch may have been a single line one
+            self.expect(TokenType::End)?;
         }
+
+        // fold the chain from the back so each else if ends up in the else body of the previous branch
+        for (token, condition, body) in branches.into_iter().rev() {
+            else_body = Some(Box::new(IfNode::new(token, condition, body, else_body)));
+        }
+
+        Ok(else_body.unwrap())
     }
 
-    fn parse_factor(&mut self) -> Box<dyn Node> {
-        let mut left = self.parse_unary();
+    // `for i = a to b step s => ...` or `for x in iterable => ...`, as an expression (collect) the loop
+    // evaluates to a list of the value of each iteration
+    fn parse_for(&mut self, collect: bool) -> ParseResult {
+        let token = self.expect(TokenType::For)?;
+        let var = self.expect(TokenType::Identifier)?;
 
-        if self.at_end() {
-            left
+        if self.peek()?.get_type() == TokenType::In {
+            self.next_token()?;
+            let iterable = self.parse_expression()?;
+            let body = self.parse_loop_body()?;
+
+            return Ok(Box::new(ForInNode::new(token, var, iterable, body, collect)));
+        }
+
+        self.expect(TokenType::Equal)?;
+        let start = self.parse_expression()?;
+        self.expect(TokenType::To)?;
+        let end = self.parse_expression()?;
+
+        let step = if self.peek()?.get_type() == TokenType::Step {
+            self.next_token()?;
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
+        let body = self.parse_loop_body()?;
+        Ok(Box::new(ForNode::new(token, var, start, end, step, body, collect)))
+    }
+
+    fn parse_while(&mut self) -> ParseResult {
+        let token = self.next_token()?.clone();
+        let condition = self.parse_expression()?;
+        let body = self.parse_loop_body()?;
+
+        Ok(Box::new(WhileNode::new(token, condition, body)))
+    }
+
+    fn parse_loop_control(&mut self) -> ParseResult {
+        let token = self.next_token()?.clone();
+
+        if self.loop_depth == 0 {
+            raise_error!(ErrorType::OutsideLoop(if token.get_type() == TokenType::Break { "break" } else { "continue" }), token.take_pos());
+        }
+
+        if token.get_type() == TokenType::Break {
+            Ok(Box::new(BreakNode::new(token)))
         } else {
-            while token_matches!(self.peek(), TokenType::Times, TokenType::Divide, TokenType::Mod) {
-                let op = self.next_token().get_type();
-                let right = self.parse_unary();
-                left = Box::new(BinOpNode::new(op, left, right));
+            Ok(Box::new(ContinueNode::new(token)))
+        }
+    }
+
+    fn parse_delete(&mut self) -> ParseResult {
+        let token = self.next_token()?.clone();
+        let target = self.parse_postfix()?;
+
+        match target.to_deletion(token.clone()) {
+            Some(deletion) => Ok(deletion),
+            None => {
+                raise_error!(ErrorType::InvalidDeletion, token.take_pos());
+            }
+        }
+    }
+
+    // `try => ... catch err => ... finally => ... end`, like an if chain the end is only required when
+    // at least one of the bodies is a block
+    fn parse_try(&mut self) -> ParseResult {
+        let token = self.next_token()?.clone();
+        self.expect(TokenType::Lambda)?;
+
+        let (body, mut needs_end) = self.parse_body(&[TokenType::Catch, TokenType::Finally, TokenType::End])?;
+        let mut catch_var = None;
+        let mut catch_body = None;
+        let mut finally_body = None;
+
+        if self.continues_with(TokenType::Catch) {
+            self.next_token()?;
+
+            if self.peek()?.get_type() == TokenType::Identifier {
+                catch_var = Some(self.next_token()?.clone());
+            }
+
+            self.expect(TokenType::Lambda)?;
+            let (body, is_block) = self.parse_body(&[TokenType::Finally, TokenType::End])?;
+            needs_end |= is_block;
+            catch_body = Some(body);
+        }
+
+        if self.continues_with(TokenType::Finally) {
+            self.next_token()?;
+            self.expect(TokenType::Lambda)?;
+            let (body, is_block) = self.parse_body(&[TokenType::End])?;
+            needs_end |= is_block;
+            finally_body = Some(body);
+        }
+
+        // a try on its own would just be the body
+        if catch_body.is_none() && finally_body.is_none() {
+            self.expect(TokenType::Catch)?;
+        }
+
+        if needs_end {
+            self.skip_newlines();
+            self.expect(TokenType::End)?;
+        }
+
+        Ok(Box::new(TryNode::new(token, body, catch_var, catch_body, finally_body)))
+    }
+
+    fn parse_throw(&mut self) -> ParseResult {
+        let token = self.next_token()?.clone();
+        let value = self.parse_expression()?;
+
+        Ok(Box::new(ThrowNode::new(token, value)))
+    }
+
+    fn parse_loop_body(&mut self) -> ParseResult {
+        self.expect(TokenType::Lambda)?;
+
+        self.loop_depth += 1;
+        let body = self.parse_body(&[TokenType::End]);
+        self.loop_depth -= 1;
+        let (body, is_block) = body?;
+
+        if is_block {
+            self.expect(TokenType::End)?;
+        }
+
+        Ok(body)
+    }
+
+    fn parse_expression(&mut self) -> ParseResult {
+        self.parse_binary(LOWEST_PRECEDENCE)
+    }
+
+    // precedence climbing, parses an operand followed by every binary operator binding at least as tightly
+    // as min_precedence (and their operands). a left associative operator's right operand only takes
+    // tighter operators, so `a - b - c` is `(a - b) - c`, a right associative one's takes the same ones
+    // as well, so `a ** b ** c` is `a ** (b ** c)`
+    fn parse_binary(&mut self, min_precedence: u8) -> ParseResult {
+        let mut left = self.parse_prefix()?;
+
+        while !self.at_end() {
+            let (precedence, associativity) = match get_binary_operator(self.peek()?.get_type()) {
+                Some((precedence, associativity)) if precedence >= min_precedence => (precedence, associativity),
+                _ => break,
+            };
+
+            let op = self.next_token()?.clone();
+
+            // `x is not y` is `not (x is y)`
+            let negation = if op.get_type() == TokenType::Is && self.peek()?.get_type() == TokenType::Not {
+                Some(self.next_token()?.clone())
+            } else {
+                None
             };
 
-            left
+            let right = match associativity {
+                Associativity::Left => self.parse_binary(precedence + 1)?,
+                Associativity::Right => self.parse_binary(precedence)?,
+            };
+
+            left = Box::new(BinOpNode::new(op, left, right));
+
+            if let Some(not) = negation {
+                left = Box::new(UnaryNode::new(not, left));
+            }
         }
+
+        Ok(left)
     }
 
-    fn parse_unary(&mut self) -> Box<dyn Node> {
-        if self.at_end() {
-            self.parse_primary()
+    // a prefix operator's operand takes the binary operators binding tighter than it, so `not a == b` is
+    // `not (a == b)` and `-2 ** 2` is `-(2 ** 2)`
+    fn parse_prefix(&mut self) -> ParseResult {
+        self.nested(Self::parse_unnested_prefix)
+    }
+
+    fn parse_unnested_prefix(&mut self) -> ParseResult {
+        let precedence = match self.peek()?.get_type() {
+            TokenType::Not => NOT_PRECEDENCE,
+            TokenType::Plus | TokenType::Minus => UNARY_PRECEDENCE,
+            _ => return self.parse_postfix(),
+        };
+
+        let op = self.next_token()?.clone();
+        Ok(Box::new(UnaryNode::new(op, self.parse_binary(precedence + 1)?)))
+    }
+
+    // calls, method calls, attributes, indexing and slicing, all of which can be chained: `matrix[0][1]`,
+    // `makeAdder(1)(2)`, `name.trim().upper()`, `error.traceback[0]`
+    fn parse_postfix(&mut self) -> ParseResult {
+        let mut expr = self.parse_primary()?;
+
+        while !self.at_end() {
+            expr = match self.peek()?.get_type() {
+                TokenType::Lparen => {
+                    let token = self.next_token()?.clone();
+                    let args = self.parse_expression_list(&token, TokenType::Rparen)?;
+                    Box::new(CallNode::new(token, expr, args))
+                }
+                TokenType::Lbracket => self.parse_subscript(expr)?,
+                TokenType::Period => {
+                    self.next_token()?;
+                    let name = self.expect(TokenType::Identifier)?;
+
+                    // without parentheses it's an attribute, `error.message`
+                    if self.at_end() || self.peek()?.get_type() != TokenType::Lparen {
+                        Box::new(AttributeNode::new(name, expr))
+                    } else {
+                        let open = self.next_token()?.clone();
+                        let args = self.parse_expression_list(&open, TokenType::Rparen)?;
+                        Box::new(MethodCallNode::new(name, expr, args))
+                    }
+                }
+                _ => break,
+            };
+        }
+
+        Ok(expr)
+    }
+
+    // `[index]` or `[start:end]` where both bounds of the slice are optional
+    fn parse_subscript(&mut self, target: Box<dyn Node>) -> ParseResult {
+        let token = self.expect(TokenType::Lbracket)?;
+
+        let start = if self.peek()?.get_type() == TokenType::Colon {
+            None
         } else {
-            if token_matches!(self.peek(), TokenType::Plus, TokenType::Minus) {
-                let op = self.next_token().get_type();
-                Box::new(UnaryNode::new(op, self.parse_unary()))
-            } else {
-                self.parse_primary()
+            Some(self.parse_expression()?)
+        };
+
+        if self.peek()?.get_type() != TokenType::Colon {
+            self.expect_closing(TokenType::Rbracket, &token)?;
+
+            // start can only be missing if the next token was a colon
+            return Ok(Box::new(IndexNode::new(token, target, start.unwrap())));
+        }
+
+        self.next_token()?;
+
+        let end = if self.peek()?.get_type() == TokenType::Rbracket {
+            None
+        } else {
+            Some(self.parse_expression()?)
+        };
+
+        self.expect_closing(TokenType::Rbracket, &token)?;
+        Ok(Box::new(SliceNode::new(token, target, start, end)))
+    }
+
+    // the literal parts and `{expr}` parts of an interpolated string up to its InterpolationEnd, see
+    // Lexer::consume_string. empty literal parts are left out
+    fn parse_interpolation(&mut self, token: Token) -> ParseResult {
+        let mut parts: Vec<Box<dyn Node>> = Vec::new();
+
+        loop {
+            let literal = self.expect(TokenType::String)?;
+
+            if !literal.get_value().is_empty() {
+                parts.push(Box::new(StringNode::new(literal.get_value(), literal)));
+            }
+
+            if self.peek()?.get_type() == TokenType::InterpolationEnd {
+                self.next_token()?;
+                break;
+            }
+
+            let open = self.expect(TokenType::Lbrace)?;
+            parts.push(self.parse_expression()?);
+            self.expect_closing(TokenType::Rbrace, &open)?;
+        }
+
+        Ok(Box::new(InterpolationNode::new(token, parts)))
+    }
+
+    // `{key: value, ...}`, the opening brace has already been consumed
+    fn parse_dict(&mut self, token: Token) -> ParseResult {
+        let mut entries = Vec::new();
+
+        while self.peek()?.get_type() != TokenType::Rbrace {
+            let key = self.parse_expression()?;
+            self.expect(TokenType::Colon)?;
+            let value = self.parse_expression()?;
+            entries.push((key, value));
+
+            if self.peek()?.get_type() != TokenType::Comma {
+                break;
+            }
+
+            self.next_token()?;
+        }
+
+        self.expect_closing(TokenType::Rbrace, &token)?;
+        Ok(Box::new(DictNode::new(token, entries)))
+    }
+
+    // comma separated expressions up to and including the closing token, a trailing comma is allowed
+    fn parse_expression_list(&mut self, open: &Token, close: TokenType) -> Result<Vec<Box<dyn Node>>, GlassError> {
+        let mut expressions = Vec::new();
+
+        while self.peek()?.get_type() != close {
+            expressions.push(self.parse_expression()?);
+
+            if self.peek()?.get_type() != TokenType::Comma {
+                break;
             }
+
+            self.next_token()?;
         }
+
+        self.expect_closing(close, open)?;
+        Ok(expressions)
     }
 
-    fn parse_primary(&mut self) -> Box<dyn Node> {
-        let primary = self.next_token();
+    fn parse_primary(&mut self) -> ParseResult {
+        // compound expressions consume their own keyword
+        match self.peek()?.get_type() {
+            TokenType::If => return self.parse_if(),
+            TokenType::For => return self.parse_for(true),
+            _ => {}
+        }
 
-        match primary.get_type() {
-            TokenType::True => Box::new(BoolNode::new(true)),
-            TokenType::False => Box::new(BoolNode::new(false)),
-            TokenType::Void => Box::new(VoidNode::new()),
-            TokenType::String => Box::new(StringNode::new(primary.get_value())),
+        let primary = self.next_token()?.clone();
+
+        Ok(match primary.get_type() {
+            TokenType::True => Box::new(BoolNode::new(true, primary)),
+            TokenType::False => Box::new(BoolNode::new(false, primary)),
+            TokenType::Void => Box::new(VoidNode::new(primary)),
+            TokenType::String => Box::new(StringNode::new(primary.get_value(), primary)),
+            TokenType::InterpolationStart => self.parse_interpolation(primary)?,
+            // `function` on its own is the type, followed by parameters it's an anonymous function
+            TokenType::Function if !self.at_end() && self.peek()?.get_type() == TokenType::Lparen => self.parse_anonymous_function(primary)?,
+            TokenType::Num => Box::new(TypeNode::new(Type::Number, primary)),
+            TokenType::Str => Box::new(TypeNode::new(Type::String, primary)),
+            TokenType::Bool => Box::new(TypeNode::new(Type::Bool, primary)),
+            TokenType::List => Box::new(TypeNode::new(Type::List, primary)),
+            TokenType::Dict => Box::new(TypeNode::new(Type::Dictionary, primary)),
+            TokenType::Function => Box::new(TypeNode::new(Type::Function, primary)),
+            TokenType::Identifier => Box::new(VarAccessNode::new(primary)),
+            TokenType::Lbracket => {
+                let elements = self.parse_expression_list(&primary, TokenType::Rbracket)?;
+                Box::new(ListNode::new(primary, elements))
+            }
+            TokenType::Lbrace => self.parse_dict(primary)?,
             TokenType::Lparen => {
-                let expr = self.parse_expression();
-                self.expect(TokenType::Rparen);
+                let expr = self.parse_expression()?;
+                self.expect_closing(TokenType::Rparen, &primary)?;
                 expr
             }
             TokenType::Number => {
                 let parsed = primary.get_value().parse();
 
                 if let Ok(value) = parsed {
-                    Box::new(NumberNode::new(value))
+                    Box::new(NumberNode::new(value, primary))
                 } else {
-                    dispatch_error!(ErrorType::GenericError("GlassLang failed to parse the lexed number. Please report this error to GitHub"), primary.take_pos());
+                    raise_error!(ErrorType::GenericError("GlassLang failed to parse the lexed number. Please report this error to GitHub"), primary.take_pos());
                 }
             }
-            // todo: implement std functions... definitely streamline this in the future
-            // TokenType::Print => Box::new(),
-            // TokenType::Println => Box::new(),
-            _ => todo!()
-        }
+            token_type => {
+                raise_error!(ErrorType::UnexpectedToken(token_type), primary.take_pos());
+            }
+        })
     }
 
     // returns the token and doesn't advance the index
     // used for lookahead (cases such as variable declaration)
-    fn peek(&self) -> &Token {
-        &self.tokens[self.index]
+    fn peek(&self) -> Result<&Token, GlassError> {
+        if self.at_end() {
+            return Err(self.end_of_file());
+        }
+
+        Ok(&self.tokens[self.index])
     }
 
     // returns the token and advances the index if it's not the end of the file
-    fn next_token(&mut self) -> &Token {
+    fn next_token(&mut self) -> Result<&Token, GlassError> {
         if self.at_end() {
-            dispatch_error!(ErrorType::ReachedEndOfFile);
+            return Err(self.end_of_file());
         }
 
         let token = &self.tokens[self.index];
         self.index += 1;
-        token
+        Ok(token)
+    }
+
+    // reported at the last token, which is the newline ending the file
+    fn end_of_file(&self) -> GlassError {
+        GlassError::new(ErrorType::ReachedEndOfFile, self.tokens.last().map(|token| token.take_pos()))
+    }
+
+    fn skip_newlines(&mut self) {
+        while !self.at_end() && self.tokens[self.index].get_type() == TokenType::Newline {
+            self.index += 1;
+        }
+    }
+
+    // a single line body ends at its newline, so the `else`, `catch` or `finally` continuing the construct
+    // is on a later line. skips to it if it's there, otherwise nothing is consumed
+    fn continues_with(&mut self, token_type: TokenType) -> bool {
+        let next = self.tokens[self.index..].iter().find(|token| token.get_type() != TokenType::Newline);
+
+        if next.is_some_and(|token| token.get_type() == token_type) {
+            self.skip_newlines();
+            true
+        } else {
+            false
+        }
     }
 
     // if the end of the file has been reached (or will be reached when next_token is called)
@@ -171,12 +832,20 @@ impl<'a> Parser<'a> {
         self.index >= self.tokens.len()
     }
 
-    // expect will advance the index if the token matches, otherwise it will throw
-    fn expect(&mut self, token_type: TokenType) {
-        let current = self.next_token();
+    // expect will advance the index and return the token if it matches, otherwise it will throw
+    fn expect(&mut self, token_type: TokenType) -> Result<Token, GlassError> {
+        let current = self.next_token()?;
 
         if current.get_type() != token_type {
-            dispatch_error!(ErrorType::UnexpectedExpression(token_type, current.get_type()), current.take_pos());
+            raise_error!(ErrorType::UnexpectedExpression(token_type, current.get_type()), current.take_pos());
         }
+
+        Ok(current.clone())
     }
-}
\ No newline at end of file
+
+    // the closing bracket of a pair, if it's missing the opening one is pointed out as well since the
+    // error is usually reported far away from it
+    fn expect_closing(&mut self, close: TokenType, open: &Token) -> Result<Token, GlassError> {
+        self.expect(close).map_err(|error| error.with_label(open.take_pos(), "unclosed bracket opened here"))
+    }
+}
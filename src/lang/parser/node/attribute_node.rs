@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::delete_node::DeleteNode;
+use crate::lang::parser::node::index_assign_node::IndexAssignNode;
+use crate::lang::parser::node::Node;
+use crate::lang::parser::node::string_node::StringNode;
+
+// `target.name`, the token is the attribute name. only dictionaries have attributes, which are their
+// string keys, so it's the same as `target["name"]` when assigned to or deleted
+pub struct AttributeNode {
+    token: Token,
+    target: Box<dyn Node>,
+}
+
+impl AttributeNode {
+    pub fn new(token: Token, target: Box<dyn Node>) -> Self {
+        Self { token, target }
+    }
+
+    pub fn get_target(&self) -> &dyn Node {
+        self.target.as_ref()
+    }
+
+    pub fn get_name(&self) -> String {
+        self.token.get_value()
+    }
+
+    fn into_key(self) -> (Token, Box<dyn Node>, Box<dyn Node>) {
+        let key = Box::new(StringNode::new(self.token.get_value(), self.token.clone()));
+        (self.token, self.target, key)
+    }
+}
+
+impl Node for AttributeNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_attribute_node(self)
+    }
+
+    fn to_assignment(self: Box<Self>, op: Token, value: Box<dyn Node>) -> Option<Box<dyn Node>> {
+        let (token, target, key) = self.into_key();
+        Some(Box::new(IndexAssignNode::new(token, target, key, op, value)))
+    }
+
+    fn to_deletion(self: Box<Self>, token: Token) -> Option<Box<dyn Node>> {
+        let (_, target, key) = self.into_key();
+        Some(Box::new(DeleteNode::new(token, target, key)))
+    }
+}
+
+impl Display for AttributeNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Attribute({}.{})", self.target, self.token.get_value())
+    }
+}
@@ -1,35 +1,43 @@
 use std::fmt::Display;
 
+use crate::lang::interpreter::{Interpreter, VisitResult};
 use crate::lang::lexer::token::Token;
-use crate::lang::lexer::token::token_type::TokenType;
 use crate::lang::parser::node::Node;
 
 pub struct BinOpNode {
-    op: TokenType,
+    op: Token,
     left: Box<dyn Node>,
     right: Box<dyn Node>,
 }
 
 
 impl BinOpNode {
-    pub fn new(op: TokenType, left: Box<dyn Node>, right: Box<dyn Node>) -> Self {
+    pub fn new(op: Token, left: Box<dyn Node>, right: Box<dyn Node>) -> Self {
         Self { op, left, right }
     }
+
+    pub fn get_left(&self) -> &dyn Node {
+        self.left.as_ref()
+    }
+
+    pub fn get_right(&self) -> &dyn Node {
+        self.right.as_ref()
+    }
 }
 
 impl Node for BinOpNode {
     fn get_token(&self) -> &Token {
-        todo!()
+        &self.op
     }
 
-    fn visit(&self, visitor: ()) {
-        todo!()
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_bin_op_node(self)
     }
 }
 
 impl Display for BinOpNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "BinOpNode ({} {} {})", self.left, self.op.into(): &str, self.right)
+        write!(f, "BinOpNode ({} {} {})", self.left, <&str>::from(self.op.get_type()), self.right)
     }
 }
 
@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// a sequence of newline separated statements, used for whole programs as well as bodies
+pub struct BlockNode {
+    statements: Vec<Box<dyn Node>>,
+    token: Token,
+}
+
+impl BlockNode {
+    pub fn new(statements: Vec<Box<dyn Node>>, token: Token) -> Self {
+        Self { statements, token }
+    }
+
+    pub fn get_statements(&self) -> &[Box<dyn Node>] {
+        &self.statements
+    }
+}
+
+impl Node for BlockNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_block_node(self)
+    }
+}
+
+impl Display for BlockNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let statements: Vec<String> = self.statements.iter().map(|statement| statement.to_string()).collect();
+        write!(f, "Block [{}]", statements.join(", "))
+    }
+}
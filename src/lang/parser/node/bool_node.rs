@@ -1,30 +1,36 @@
 use std::fmt::Display;
 
+use crate::lang::interpreter::{Interpreter, VisitResult};
 use crate::lang::lexer::token::Token;
 use crate::lang::parser::node::Node;
 
 pub struct BoolNode {
     value: bool,
+    token: Token,
 }
 
 impl BoolNode {
-    pub fn new(value: bool) -> Self {
-        Self { value }
+    pub fn new(value: bool, token: Token) -> Self {
+        Self { value, token }
+    }
+
+    pub fn get_value(&self) -> bool {
+        self.value
     }
 }
 
 impl Node for BoolNode {
     fn get_token(&self) -> &Token {
-        todo!()
+        &self.token
     }
 
-    fn visit(&self, visitor: ()) {
-        todo!()
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_bool_node(self)
     }
 }
 
 impl Display for BoolNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "BOol({})", self.value)
+        write!(f, "Bool({})", self.value)
     }
-}
\ No newline at end of file
+}
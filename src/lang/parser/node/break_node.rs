@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+pub struct BreakNode {
+    token: Token,
+}
+
+impl BreakNode {
+    pub fn new(token: Token) -> Self {
+        Self { token }
+    }
+}
+
+impl Node for BreakNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_break_node(self)
+    }
+}
+
+impl Display for BreakNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Break")
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+pub struct CallNode {
+    token: Token,
+    callee: Box<dyn Node>,
+    args: Vec<Box<dyn Node>>,
+}
+
+impl CallNode {
+    pub fn new(token: Token, callee: Box<dyn Node>, args: Vec<Box<dyn Node>>) -> Self {
+        Self { token, callee, args }
+    }
+
+    pub fn get_callee(&self) -> &dyn Node {
+        self.callee.as_ref()
+    }
+
+    pub fn get_args(&self) -> &[Box<dyn Node>] {
+        &self.args
+    }
+}
+
+impl Node for CallNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_call_node(self)
+    }
+}
+
+impl Display for CallNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let args: Vec<String> = self.args.iter().map(|arg| arg.to_string()).collect();
+        write!(f, "Call({}({}))", self.callee, args.join(", "))
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+pub struct ContinueNode {
+    token: Token,
+}
+
+impl ContinueNode {
+    pub fn new(token: Token) -> Self {
+        Self { token }
+    }
+}
+
+impl Node for ContinueNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_continue_node(self)
+    }
+}
+
+impl Display for ContinueNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Continue")
+    }
+}
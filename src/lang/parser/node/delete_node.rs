@@ -0,0 +1,42 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// `delete target[index]`, removes a key from a dictionary or an item from a list
+pub struct DeleteNode {
+    token: Token,
+    target: Box<dyn Node>,
+    index: Box<dyn Node>,
+}
+
+impl DeleteNode {
+    pub fn new(token: Token, target: Box<dyn Node>, index: Box<dyn Node>) -> Self {
+        Self { token, target, index }
+    }
+
+    pub fn get_target(&self) -> &dyn Node {
+        self.target.as_ref()
+    }
+
+    pub fn get_index(&self) -> &dyn Node {
+        self.index.as_ref()
+    }
+}
+
+impl Node for DeleteNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_delete_node(self)
+    }
+}
+
+impl Display for DeleteNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Delete({}[{}])", self.target, self.index)
+    }
+}
@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// a key expression and the value expression it maps to
+pub type Entry = (Box<dyn Node>, Box<dyn Node>);
+
+pub struct DictNode {
+    token: Token,
+    entries: Vec<Entry>,
+}
+
+impl DictNode {
+    pub fn new(token: Token, entries: Vec<Entry>) -> Self {
+        Self { token, entries }
+    }
+
+    pub fn get_entries(&self) -> &[Entry] {
+        &self.entries
+    }
+}
+
+impl Node for DictNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_dict_node(self)
+    }
+}
+
+impl Display for DictNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let entries: Vec<String> = self.entries.iter().map(|(key, value)| format!("{}: {}", key, value)).collect();
+        write!(f, "Dict{{{}}}", entries.join(", "))
+    }
+}
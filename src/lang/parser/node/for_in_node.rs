@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// `for x in iterable => body`
+pub struct ForInNode {
+    token: Token,
+    var: Token,
+    iterable: Box<dyn Node>,
+    body: Box<dyn Node>,
+    collect: bool, // used as an expression, so the values of each iteration are collected into a list
+}
+
+impl ForInNode {
+    pub fn new(token: Token, var: Token, iterable: Box<dyn Node>, body: Box<dyn Node>, collect: bool) -> Self {
+        Self { token, var, iterable, body, collect }
+    }
+
+    pub fn get_var(&self) -> String {
+        self.var.get_value()
+    }
+
+    pub fn get_iterable(&self) -> &dyn Node {
+        self.iterable.as_ref()
+    }
+
+    pub fn get_body(&self) -> &dyn Node {
+        self.body.as_ref()
+    }
+
+    pub fn collects(&self) -> bool {
+        self.collect
+    }
+}
+
+impl Node for ForInNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_for_in_node(self)
+    }
+}
+
+impl Display for ForInNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ForIn({} in {} => {})", self.var.get_value(), self.iterable, self.body)
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// `for i = start to end step step => body`, the end is exclusive
+pub struct ForNode {
+    token: Token,
+    var: Token,
+    start: Box<dyn Node>,
+    end: Box<dyn Node>,
+    step: Option<Box<dyn Node>>,
+    body: Box<dyn Node>,
+    collect: bool, // used as an expression, so the values of each iteration are collected into a list
+}
+
+impl ForNode {
+    pub fn new(token: Token, var: Token, start: Box<dyn Node>, end: Box<dyn Node>, step: Option<Box<dyn Node>>, body: Box<dyn Node>, collect: bool) -> Self {
+        Self { token, var, start, end, step, body, collect }
+    }
+
+    pub fn get_var(&self) -> String {
+        self.var.get_value()
+    }
+
+    pub fn get_start(&self) -> &dyn Node {
+        self.start.as_ref()
+    }
+
+    pub fn get_end(&self) -> &dyn Node {
+        self.end.as_ref()
+    }
+
+    pub fn get_step(&self) -> Option<&dyn Node> {
+        self.step.as_deref()
+    }
+
+    pub fn get_body(&self) -> &dyn Node {
+        self.body.as_ref()
+    }
+
+    pub fn collects(&self) -> bool {
+        self.collect
+    }
+}
+
+impl Node for ForNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_for_node(self)
+    }
+}
+
+impl Display for ForNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.step {
+            Some(step) => write!(f, "For({} = {} to {} step {} => {})", self.var.get_value(), self.start, self.end, step, self.body),
+            None => write!(f, "For({} = {} to {} => {})", self.var.get_value(), self.start, self.end, self.body),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+use std::rc::Rc;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+pub struct FuncDefNode {
+    token: Token,
+    name: Token,
+    params: Vec<Token>,
+    body: Rc<dyn Node>,
+}
+
+impl FuncDefNode {
+    pub fn new(token: Token, name: Token, params: Vec<Token>, body: Rc<dyn Node>) -> Self {
+        Self { token, name, params, body }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.get_value()
+    }
+
+    pub fn get_params(&self) -> Vec<String> {
+        self.params.iter().map(|param| param.get_value()).collect()
+    }
+
+    // shared with every function primitive created from this definition
+    pub fn get_body(&self) -> Rc<dyn Node> {
+        Rc::clone(&self.body)
+    }
+}
+
+impl Node for FuncDefNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_func_def_node(self)
+    }
+}
+
+impl Display for FuncDefNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FuncDef({}({}) {})", self.name.get_value(), self.get_params().join(", "), self.body)
+    }
+}
@@ -0,0 +1,44 @@
+use std::fmt::Display;
+use std::rc::Rc;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// an anonymous function expression, `function(x) => x * 2`
+pub struct FunctionNode {
+    token: Token,
+    params: Vec<Token>,
+    body: Rc<dyn Node>,
+}
+
+impl FunctionNode {
+    pub fn new(token: Token, params: Vec<Token>, body: Rc<dyn Node>) -> Self {
+        Self { token, params, body }
+    }
+
+    pub fn get_params(&self) -> Vec<String> {
+        self.params.iter().map(|param| param.get_value()).collect()
+    }
+
+    // shared with every function primitive created from this expression
+    pub fn get_body(&self) -> Rc<dyn Node> {
+        Rc::clone(&self.body)
+    }
+}
+
+impl Node for FunctionNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_function_node(self)
+    }
+}
+
+impl Display for FunctionNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Function(({}) {})", self.get_params().join(", "), self.body)
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// `else if` chains are nested if nodes in the else body
+pub struct IfNode {
+    token: Token,
+    condition: Box<dyn Node>,
+    body: Box<dyn Node>,
+    else_body: Option<Box<dyn Node>>,
+}
+
+impl IfNode {
+    pub fn new(token: Token, condition: Box<dyn Node>, body: Box<dyn Node>, else_body: Option<Box<dyn Node>>) -> Self {
+        Self { token, condition, body, else_body }
+    }
+
+    pub fn get_condition(&self) -> &dyn Node {
+        self.condition.as_ref()
+    }
+
+    pub fn get_body(&self) -> &dyn Node {
+        self.body.as_ref()
+    }
+
+    pub fn get_else_body(&self) -> Option<&dyn Node> {
+        self.else_body.as_deref()
+    }
+}
+
+impl Node for IfNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_if_node(self)
+    }
+}
+
+impl Display for IfNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.else_body {
+            Some(else_body) => write!(f, "If({} => {} else {})", self.condition, self.body, else_body),
+            None => write!(f, "If({} => {})", self.condition, self.body),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// `target[index] = value`, like variable assignment op can also be one of the compound operators
+pub struct IndexAssignNode {
+    token: Token,
+    target: Box<dyn Node>,
+    index: Box<dyn Node>,
+    op: Token,
+    value: Box<dyn Node>,
+}
+
+impl IndexAssignNode {
+    pub fn new(token: Token, target: Box<dyn Node>, index: Box<dyn Node>, op: Token, value: Box<dyn Node>) -> Self {
+        Self { token, target, index, op, value }
+    }
+
+    pub fn get_target(&self) -> &dyn Node {
+        self.target.as_ref()
+    }
+
+    pub fn get_index(&self) -> &dyn Node {
+        self.index.as_ref()
+    }
+
+    pub fn get_op(&self) -> &Token {
+        &self.op
+    }
+
+    pub fn get_value(&self) -> &dyn Node {
+        self.value.as_ref()
+    }
+}
+
+impl Node for IndexAssignNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_index_assign_node(self)
+    }
+}
+
+impl Display for IndexAssignNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IndexAssign({}[{}] {} {})", self.target, self.index, <&str>::from(self.op.get_type()), self.value)
+    }
+}
@@ -0,0 +1,52 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::delete_node::DeleteNode;
+use crate::lang::parser::node::index_assign_node::IndexAssignNode;
+use crate::lang::parser::node::Node;
+
+// `target[index]`
+pub struct IndexNode {
+    token: Token,
+    target: Box<dyn Node>,
+    index: Box<dyn Node>,
+}
+
+impl IndexNode {
+    pub fn new(token: Token, target: Box<dyn Node>, index: Box<dyn Node>) -> Self {
+        Self { token, target, index }
+    }
+
+    pub fn get_target(&self) -> &dyn Node {
+        self.target.as_ref()
+    }
+
+    pub fn get_index(&self) -> &dyn Node {
+        self.index.as_ref()
+    }
+}
+
+impl Node for IndexNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_index_node(self)
+    }
+
+    fn to_assignment(self: Box<Self>, op: Token, value: Box<dyn Node>) -> Option<Box<dyn Node>> {
+        Some(Box::new(IndexAssignNode::new(self.token, self.target, self.index, op, value)))
+    }
+
+    fn to_deletion(self: Box<Self>, token: Token) -> Option<Box<dyn Node>> {
+        Some(Box::new(DeleteNode::new(token, self.target, self.index)))
+    }
+}
+
+impl Display for IndexNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Index({}[{}])", self.target, self.index)
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// a string with `{expr}` parts, the parts are the literal strings and expressions in order
+pub struct InterpolationNode {
+    token: Token,
+    parts: Vec<Box<dyn Node>>,
+}
+
+impl InterpolationNode {
+    pub fn new(token: Token, parts: Vec<Box<dyn Node>>) -> Self {
+        Self { token, parts }
+    }
+
+    pub fn get_parts(&self) -> &[Box<dyn Node>] {
+        &self.parts
+    }
+}
+
+impl Node for InterpolationNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_interpolation_node(self)
+    }
+}
+
+impl Display for InterpolationNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = self.parts.iter().map(|part| part.to_string()).collect();
+        write!(f, "Interpolation({})", parts.join(", "))
+    }
+}
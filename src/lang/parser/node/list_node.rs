@@ -0,0 +1,37 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+pub struct ListNode {
+    token: Token,
+    elements: Vec<Box<dyn Node>>,
+}
+
+impl ListNode {
+    pub fn new(token: Token, elements: Vec<Box<dyn Node>>) -> Self {
+        Self { token, elements }
+    }
+
+    pub fn get_elements(&self) -> &[Box<dyn Node>] {
+        &self.elements
+    }
+}
+
+impl Node for ListNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_list_node(self)
+    }
+}
+
+impl Display for ListNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let elements: Vec<String> = self.elements.iter().map(|element| element.to_string()).collect();
+        write!(f, "List[{}]", elements.join(", "))
+    }
+}
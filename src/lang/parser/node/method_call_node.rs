@@ -0,0 +1,47 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// `target.name(args)`, the token is the method name
+pub struct MethodCallNode {
+    token: Token,
+    target: Box<dyn Node>,
+    args: Vec<Box<dyn Node>>,
+}
+
+impl MethodCallNode {
+    pub fn new(token: Token, target: Box<dyn Node>, args: Vec<Box<dyn Node>>) -> Self {
+        Self { token, target, args }
+    }
+
+    pub fn get_target(&self) -> &dyn Node {
+        self.target.as_ref()
+    }
+
+    pub fn get_name(&self) -> String {
+        self.token.get_value()
+    }
+
+    pub fn get_args(&self) -> &[Box<dyn Node>] {
+        &self.args
+    }
+}
+
+impl Node for MethodCallNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_method_call_node(self)
+    }
+}
+
+impl Display for MethodCallNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let args: Vec<String> = self.args.iter().map(|arg| arg.to_string()).collect();
+        write!(f, "MethodCall({}.{}({}))", self.target, self.token.get_value(), args.join(", "))
+    }
+}
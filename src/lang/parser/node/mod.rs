@@ -1,5 +1,6 @@
 use std::fmt::Display;
 
+use crate::lang::interpreter::{Interpreter, VisitResult};
 use crate::lang::lexer::token::Token;
 
 pub mod bin_op_node;
@@ -7,10 +8,45 @@ pub mod unary_node;
 pub mod string_node;
 pub mod number_node;
 pub mod bool_node;
+pub mod type_node;
 pub mod void_node;
+pub mod block_node;
+pub mod var_access_node;
+pub mod var_assign_node;
+pub mod func_def_node;
+pub mod function_node;
+pub mod call_node;
+pub mod return_node;
+pub mod if_node;
+pub mod for_node;
+pub mod for_in_node;
+pub mod while_node;
+pub mod break_node;
+pub mod continue_node;
+pub mod list_node;
+pub mod index_node;
+pub mod index_assign_node;
+pub mod slice_node;
+pub mod dict_node;
+pub mod delete_node;
+pub mod try_node;
+pub mod throw_node;
+pub mod interpolation_node;
+pub mod method_call_node;
+pub mod attribute_node;
 
 // todo: add macro to auto generate visitor methods and get_token methods for all nodes (and also probably display methods)
 pub trait Node: Display {
     fn get_token(&self) -> &Token;
-    fn visit(&self, visitor: ());
-}
\ No newline at end of file
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult;
+
+    // nodes which can appear on the left of an assignment turn themselves into that assignment
+    fn to_assignment(self: Box<Self>, _op: Token, _value: Box<dyn Node>) -> Option<Box<dyn Node>> {
+        None
+    }
+
+    // same idea for the target of a delete statement
+    fn to_deletion(self: Box<Self>, _token: Token) -> Option<Box<dyn Node>> {
+        None
+    }
+}
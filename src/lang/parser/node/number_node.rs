@@ -1,25 +1,31 @@
 use std::fmt::Display;
 
+use crate::lang::interpreter::{Interpreter, VisitResult};
 use crate::lang::lexer::token::Token;
 use crate::lang::parser::node::Node;
 
 pub struct NumberNode {
     value: f64,
+    token: Token,
 }
 
 impl NumberNode {
-    pub fn new(value: f64) -> Self {
-        NumberNode { value }
+    pub fn new(value: f64, token: Token) -> Self {
+        NumberNode { value, token }
+    }
+
+    pub fn get_value(&self) -> f64 {
+        self.value
     }
 }
 
 impl Node for NumberNode {
     fn get_token(&self) -> &Token {
-        todo!()
+        &self.token
     }
 
-    fn visit(&self, visitor: ()) {
-        todo!()
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_number_node(self)
     }
 }
 
@@ -27,4 +33,4 @@ impl Display for NumberNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Number({})", self.value)
     }
-}
\ No newline at end of file
+}
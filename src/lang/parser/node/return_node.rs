@@ -0,0 +1,39 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+pub struct ReturnNode {
+    token: Token,
+    value: Option<Box<dyn Node>>,
+}
+
+impl ReturnNode {
+    pub fn new(token: Token, value: Option<Box<dyn Node>>) -> Self {
+        Self { token, value }
+    }
+
+    pub fn get_value(&self) -> Option<&dyn Node> {
+        self.value.as_deref()
+    }
+}
+
+impl Node for ReturnNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_return_node(self)
+    }
+}
+
+impl Display for ReturnNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "Return({})", value),
+            None => write!(f, "Return"),
+        }
+    }
+}
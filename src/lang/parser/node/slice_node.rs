@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// `target[start:end]`, either bound can be left out
+pub struct SliceNode {
+    token: Token,
+    target: Box<dyn Node>,
+    start: Option<Box<dyn Node>>,
+    end: Option<Box<dyn Node>>,
+}
+
+impl SliceNode {
+    pub fn new(token: Token, target: Box<dyn Node>, start: Option<Box<dyn Node>>, end: Option<Box<dyn Node>>) -> Self {
+        Self { token, target, start, end }
+    }
+
+    pub fn get_target(&self) -> &dyn Node {
+        self.target.as_ref()
+    }
+
+    pub fn get_start(&self) -> Option<&dyn Node> {
+        self.start.as_deref()
+    }
+
+    pub fn get_end(&self) -> Option<&dyn Node> {
+        self.end.as_deref()
+    }
+}
+
+impl Node for SliceNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_slice_node(self)
+    }
+}
+
+impl Display for SliceNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let start = self.start.as_ref().map_or(String::new(), |start| start.to_string());
+        let end = self.end.as_ref().map_or(String::new(), |end| end.to_string());
+        write!(f, "Slice({}[{}:{}])", self.target, start, end)
+    }
+}
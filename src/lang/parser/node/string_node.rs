@@ -1,25 +1,31 @@
 use std::fmt::Display;
 
+use crate::lang::interpreter::{Interpreter, VisitResult};
 use crate::lang::lexer::token::Token;
 use crate::lang::parser::node::Node;
 
 pub struct StringNode {
     value: String,
+    token: Token,
 }
 
 impl StringNode {
-    pub fn new(value: String) -> Self {
-        Self { value }
+    pub fn new(value: String, token: Token) -> Self {
+        Self { value, token }
+    }
+
+    pub fn get_value(&self) -> &str {
+        &self.value
     }
 }
 
 impl Node for StringNode {
     fn get_token(&self) -> &Token {
-        todo!()
+        &self.token
     }
 
-    fn visit(&self, visitor: ()) {
-        todo!()
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_string_node(self)
     }
 }
 
@@ -27,4 +33,4 @@ impl Display for StringNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "String({})", self.value)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,36 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+pub struct ThrowNode {
+    token: Token,
+    value: Box<dyn Node>,
+}
+
+impl ThrowNode {
+    pub fn new(token: Token, value: Box<dyn Node>) -> Self {
+        Self { token, value }
+    }
+
+    pub fn get_value(&self) -> &dyn Node {
+        self.value.as_ref()
+    }
+}
+
+impl Node for ThrowNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_throw_node(self)
+    }
+}
+
+impl Display for ThrowNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Throw({})", self.value)
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// `try => ... catch err => ... finally => ... end`, at least one of catch and finally is present
+pub struct TryNode {
+    token: Token,
+    body: Box<dyn Node>,
+    catch_var: Option<Token>,
+    catch_body: Option<Box<dyn Node>>,
+    finally_body: Option<Box<dyn Node>>,
+}
+
+impl TryNode {
+    pub fn new(token: Token, body: Box<dyn Node>, catch_var: Option<Token>, catch_body: Option<Box<dyn Node>>, finally_body: Option<Box<dyn Node>>) -> Self {
+        Self { token, body, catch_var, catch_body, finally_body }
+    }
+
+    pub fn get_body(&self) -> &dyn Node {
+        self.body.as_ref()
+    }
+
+    // the name the error is bound to in the catch body, if any
+    pub fn get_catch_var(&self) -> Option<String> {
+        self.catch_var.as_ref().map(|var| var.get_value())
+    }
+
+    pub fn get_catch_body(&self) -> Option<&dyn Node> {
+        self.catch_body.as_deref()
+    }
+
+    pub fn get_finally_body(&self) -> Option<&dyn Node> {
+        self.finally_body.as_deref()
+    }
+}
+
+impl Node for TryNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_try_node(self)
+    }
+}
+
+impl Display for TryNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Try({}", self.body)?;
+
+        if let Some(catch_body) = &self.catch_body {
+            match self.get_catch_var() {
+                Some(var) => write!(f, " catch {} => {}", var, catch_body)?,
+                None => write!(f, " catch => {}", catch_body)?,
+            }
+        }
+
+        if let Some(finally_body) = &self.finally_body {
+            write!(f, " finally => {}", finally_body)?;
+        }
+
+        write!(f, ")")
+    }
+}
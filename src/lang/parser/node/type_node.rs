@@ -0,0 +1,38 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::interpreter::primitive::Type;
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// a type keyword used as a value, e.g. the `num` in `x is num`
+pub struct TypeNode {
+    value: Type,
+    token: Token,
+}
+
+impl TypeNode {
+    pub fn new(value: Type, token: Token) -> Self {
+        Self { value, token }
+    }
+
+    pub fn get_value(&self) -> Type {
+        self.value
+    }
+}
+
+impl Node for TypeNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_type_node(self)
+    }
+}
+
+impl Display for TypeNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Type({})", self.value)
+    }
+}
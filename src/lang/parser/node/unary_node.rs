@@ -1,32 +1,36 @@
 use std::fmt::Display;
 
+use crate::lang::interpreter::{Interpreter, VisitResult};
 use crate::lang::lexer::token::Token;
-use crate::lang::lexer::token::token_type::TokenType;
 use crate::lang::parser::node::Node;
 
 pub struct UnaryNode {
-    op: TokenType,
+    op: Token,
     expr: Box<dyn Node>,
 }
 
 impl UnaryNode {
-    pub fn new(op: TokenType, expr: Box<dyn Node>) -> Self {
+    pub fn new(op: Token, expr: Box<dyn Node>) -> Self {
         UnaryNode { op, expr }
     }
+
+    pub fn get_expr(&self) -> &dyn Node {
+        self.expr.as_ref()
+    }
 }
 
 impl Node for UnaryNode {
     fn get_token(&self) -> &Token {
-        todo!()
+        &self.op
     }
 
-    fn visit(&self, visitor: ()) {
-        todo!()
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_unary_node(self)
     }
 }
 
 impl Display for UnaryNode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Unary({} {})", self.op.into(): &str, self.expr)
+        write!(f, "Unary({} {})", <&str>::from(self.op.get_type()), self.expr)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+use crate::lang::parser::node::var_assign_node::VarAssignNode;
+
+pub struct VarAccessNode {
+    token: Token,
+}
+
+impl VarAccessNode {
+    pub fn new(token: Token) -> Self {
+        Self { token }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.token.get_value()
+    }
+}
+
+impl Node for VarAccessNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_var_access_node(self)
+    }
+
+    fn to_assignment(self: Box<Self>, op: Token, value: Box<dyn Node>) -> Option<Box<dyn Node>> {
+        Some(Box::new(VarAssignNode::new(self.token, op, value)))
+    }
+}
+
+impl Display for VarAccessNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VarAccess({})", self.token.get_value())
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+// covers plain assignment as well as the compound operators (+=, -=, ...), which one is decided by op
+pub struct VarAssignNode {
+    token: Token,
+    op: Token,
+    value: Box<dyn Node>,
+}
+
+impl VarAssignNode {
+    pub fn new(token: Token, op: Token, value: Box<dyn Node>) -> Self {
+        Self { token, op, value }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.token.get_value()
+    }
+
+    pub fn get_op(&self) -> &Token {
+        &self.op
+    }
+
+    pub fn get_value(&self) -> &dyn Node {
+        self.value.as_ref()
+    }
+}
+
+impl Node for VarAssignNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_var_assign_node(self)
+    }
+}
+
+impl Display for VarAssignNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VarAssign({} {} {})", self.token.get_value(), <&str>::from(self.op.get_type()), self.value)
+    }
+}
@@ -1,23 +1,26 @@
 use std::fmt::Display;
 
+use crate::lang::interpreter::{Interpreter, VisitResult};
 use crate::lang::lexer::token::Token;
 use crate::lang::parser::node::Node;
 
-pub struct VoidNode {}
+pub struct VoidNode {
+    token: Token,
+}
 
 impl VoidNode {
-    pub fn new() -> VoidNode {
-        VoidNode {}
+    pub fn new(token: Token) -> VoidNode {
+        VoidNode { token }
     }
 }
 
 impl Node for VoidNode {
     fn get_token(&self) -> &Token {
-        todo!()
+        &self.token
     }
 
-    fn visit(&self, visitor: ()) {
-        todo!()
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_void_node(self)
     }
 }
 
@@ -25,4 +28,4 @@ impl Display for VoidNode {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "Void")
     }
-}
\ No newline at end of file
+}
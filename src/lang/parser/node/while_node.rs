@@ -0,0 +1,41 @@
+use std::fmt::Display;
+
+use crate::lang::interpreter::{Interpreter, VisitResult};
+use crate::lang::lexer::token::Token;
+use crate::lang::parser::node::Node;
+
+pub struct WhileNode {
+    token: Token,
+    condition: Box<dyn Node>,
+    body: Box<dyn Node>,
+}
+
+impl WhileNode {
+    pub fn new(token: Token, condition: Box<dyn Node>, body: Box<dyn Node>) -> Self {
+        Self { token, condition, body }
+    }
+
+    pub fn get_condition(&self) -> &dyn Node {
+        self.condition.as_ref()
+    }
+
+    pub fn get_body(&self) -> &dyn Node {
+        self.body.as_ref()
+    }
+}
+
+impl Node for WhileNode {
+    fn get_token(&self) -> &Token {
+        &self.token
+    }
+
+    fn visit(&self, interpreter: &mut Interpreter) -> VisitResult {
+        interpreter.visit_while_node(self)
+    }
+}
+
+impl Display for WhileNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "While({} => {})", self.condition, self.body)
+    }
+}
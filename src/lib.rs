@@ -0,0 +1,8 @@
+pub use crate::engine::Glass;
+pub use crate::errorsystem::GlassError;
+pub use crate::lang::interpreter::primitive::native_function::{Arity, NativeResult};
+pub use crate::lang::interpreter::primitive::Primitive;
+
+pub mod errorsystem;
+pub mod lang;
+mod engine;
@@ -1,24 +1,49 @@
-#![feature(type_ascription, backtrace)]
 
-use std::{fs, panic};
+use std::{fs, io, panic};
 use std::backtrace::Backtrace;
 use std::time::Instant;
 
 use clap::{App, Arg};
 use git_version::git_version;
 
-use crate::errorsystem::error_type::ErrorType;
-use crate::lang::lexer::Lexer;
-use crate::lang::parser::Parser;
-use crate::lang::REPL;
+use rust_glass::{errorsystem, Glass, GlassError};
+use rust_glass::errorsystem::error_type::ErrorType;
+use rust_glass::errorsystem::explain;
+use rust_glass::lang::interpreter::DEFAULT_MAX_DEPTH;
+use rust_glass::lang::lexer::Lexer;
+use rust_glass::lang::parser::{DEFAULT_MAX_ERRORS, Parser};
 
-mod errorsystem;
-mod lang;
+use crate::repl::REPL;
+
+mod repl;
+
+// reports the error and exits, only the binary decides that an error is fatal
+macro_rules! dispatch_error {
+    ($error: expr) => {
+        exit_with(vec![GlassError::new($error, None)])
+    };
+}
+
+fn exit_with(errors: Vec<GlassError>) -> ! {
+    // the reader of a pipe going away (`rust_glass numbers.glass | head -1`) just means nothing else is
+    // wanted, so like other command line tools the program stops quietly
+    if let [error] = errors.as_slice() {
+        if let ErrorType::WriteFailed(io::ErrorKind::BrokenPipe) = error.get_error_type() {
+            std::process::exit(0);
+        }
+    }
+
+    for error in &errors {
+        errorsystem::report(error);
+    }
+
+    std::process::exit(1);
+}
 
 fn main() {
     panic::set_hook(Box::new(|info| {
         let backtrace = if cfg!(debug_assertions) { // only print backtrace in debug mode
-            Some(Backtrace::capture())
+            Some(Box::new(Backtrace::capture()))
         } else {
             None
         };
@@ -42,41 +67,92 @@ fn main() {
             .long("tokens")
             .short("t")
             .help("Shows the tokens that were lexed"))
+        .arg(Arg::with_name("max-errors")
+            .long("max-errors")
+            .takes_value(true)
+            .validator(|value| match value.parse::<usize>() {
+                Ok(max) if max > 0 => Ok(()),
+                _ => Err(String::from("must be a positive whole number")),
+            })
+            .help("The most syntax errors reported before parsing stops"))
+        .arg(Arg::with_name("max-depth")
+            .long("max-depth")
+            .takes_value(true)
+            .validator(|value| match value.parse::<usize>() {
+                Ok(max) if max > 0 => Ok(()),
+                _ => Err(String::from("must be a positive whole number")),
+            })
+            .help("The deepest function calls can be nested before a RecursionLimit error is raised"))
+        .arg(Arg::with_name("color")
+            .long("color")
+            .takes_value(true)
+            .possible_values(&["never", "always", "auto"])
+            .default_value("auto")
+            .help("When to color error messages, auto only colors them when printing to a terminal"))
+        .arg(Arg::with_name("error-format")
+            .long("error-format")
+            .takes_value(true)
+            .possible_values(&["human", "json"])
+            .default_value("human")
+            .help("How errors are reported, json prints one object per line for tools to consume"))
+        .arg(Arg::with_name("explain")
+            .long("explain")
+            .takes_value(true)
+            .value_name("code")
+            .help("Explains an error code, e.g. --explain G0020"))
         .get_matches();
 
-    let filename;
+    if let Some(code) = matches.value_of("explain") {
+        match explain::get_explanation(code) {
+            Some(explanation) => println!("{}", explanation),
+            None => {
+                eprintln!("'{}' is not a Glass error code", code);
+                std::process::exit(1);
+            }
+        }
+
+        return;
+    }
+
+    errorsystem::set_json(matches.value_of("error-format") == Some("json"));
+
+    errorsystem::set_color(match matches.value_of("color") {
+        Some("always") => true,
+        Some("never") => false,
+        _ => atty::is(atty::Stream::Stderr),
+    });
+
     let debugging = matches.is_present("debug");
     let token_debug = matches.is_present("tokens");
+    let max_errors = matches.value_of("max-errors").map_or(DEFAULT_MAX_ERRORS, |value| value.parse().unwrap());
+    let max_depth = matches.value_of("max-depth").map_or(DEFAULT_MAX_DEPTH, |value| value.parse().unwrap());
 
-    match matches.value_of("filename") {
-        Some(value) => { filename = value; }
+    let filename = match matches.value_of("filename") {
+        Some(value) => value,
         None => {
-            let mut repl = REPL::new();
+            let mut repl = REPL::new(max_errors, max_depth);
             repl.run();
             return;
         }
-    }
-
-    // todo: pass this to lang instead to be handled
+    };
 
     let src = match fs::read_to_string(filename) {
         Ok(contents) => contents,
         Err(_) => {
-            dispatch_error!(ErrorType::UnknownFile(filename));
+            dispatch_error!(ErrorType::UnknownFile(filename.to_string()));
         }
     };
 
     let src = src.as_str();
 
+    // the tokens and tree are only needed for debugging, so they're produced separately from the run
     if debugging || token_debug {
-        let mut debug = Lexer::new(filename, src);
-
         let start = Instant::now();
-        let tokens = debug.lex();
+        let tokens = Lexer::new(filename, src).lex().unwrap_or_else(|error| exit_with(vec![error]));
         let end = Instant::now();
 
         if token_debug {
-            for token in tokens {
+            for token in &tokens {
                 println!("{}", token);
             }
         }
@@ -84,14 +160,21 @@ fn main() {
         if debugging {
             let nanos = end.duration_since(start).as_nanos();
             println!("Lexing took {} nanos, {} millis", nanos, nanos as f64 / 1_000_000f64);
+
+            let parsed = Parser::new(tokens, max_errors).parse().unwrap_or_else(|errors| exit_with(errors));
+            println!("{}", parsed);
         }
     }
 
-    let mut lexer = Lexer::new(filename, src);
-    let mut parser = Parser::new(lexer.lex());
-    let parsed = parser.parse();
+    let mut glass = Glass::with_limits(max_errors, max_depth);
+
+    let start = Instant::now();
+    let result = glass.eval_source(filename, src).unwrap_or_else(|errors| exit_with(errors));
+    let end = Instant::now();
 
     if debugging {
-        println!("{}", parsed);
+        let nanos = end.duration_since(start).as_nanos();
+        println!("{}", result);
+        println!("Running took {} nanos, {} millis", nanos, nanos as f64 / 1_000_000f64);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,68 @@
+use std::io;
+use std::io::Write;
+
+use rust_glass::errorsystem;
+use rust_glass::errorsystem::error_type::ErrorType;
+use rust_glass::Primitive;
+use rust_glass::Glass;
+
+const REPL_FILENAME: &str = "REPL";
+
+#[allow(clippy::upper_case_acronyms)]
+pub struct REPL {
+    glass: Glass,
+}
+
+impl REPL {
+    pub fn new(max_errors: usize, max_depth: usize) -> Self {
+        REPL {
+            glass: Glass::with_limits(max_errors, max_depth),
+        }
+    }
+
+    pub fn run(&mut self) {
+        let mut source = String::new();
+
+        loop {
+            let mut input = String::new();
+            print!("{}", if source.is_empty() { "> " } else { "... " });
+            io::stdout().flush().unwrap();
+
+            if io::stdin().read_line(&mut input).unwrap() == 0 {
+                println!();
+
+                // EOF, e.g. ctrl-d. in the middle of a block it only throws the block away
+                if source.is_empty() {
+                    break;
+                }
+
+                source.clear();
+                continue;
+            }
+
+            // a blank line also cancels a block, and is ignored anywhere else
+            if input.trim().is_empty() {
+                source.clear();
+                continue;
+            }
+
+            source.push_str(&input);
+
+            // errors are reported and the input is discarded, the session (and its scope) lives on
+            match self.glass.eval_source(REPL_FILENAME, source.trim()) {
+                // the only problem is that a block (a function, loop, if or try) isn't closed yet, so the
+                // next lines are read as part of the same input until it is
+                Err(errors) if errors.iter().all(|error| matches!(error.get_error_type(), ErrorType::ReachedEndOfFile)) => continue,
+                Ok(Primitive::Void) => {}
+                Ok(result) => println!("{}", result),
+                Err(errors) => {
+                    for error in &errors {
+                        errorsystem::report(error);
+                    }
+                }
+            }
+
+            source.clear();
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// helpers shared by the integration tests, not every test file uses all of them
+#![allow(dead_code)]
+
+use rust_glass::{Glass, GlassError, Primitive};
+
+pub fn eval(glass: &mut Glass, source: &str) -> Primitive {
+    match glass.eval_str(source) {
+        Ok(value) => value,
+        Err(errors) => panic!("'{}' failed to evaluate: {:?}", source, errors),
+    }
+}
+
+// the errors of source evaluated in a new session, which must fail
+pub fn errors(source: &str) -> Vec<GlassError> {
+    match Glass::new().eval_str(source) {
+        Ok(value) => panic!("'{}' evaluated to {:?} instead of failing", source, value),
+        Err(errors) => errors,
+    }
+}
+
+// a global of the session as it would be printed
+pub fn global(glass: &Glass, name: &str) -> String {
+    match glass.get_global(name) {
+        Some(value) => value.to_string(),
+        None => panic!("'{}' is undefined", name),
+    }
+}
@@ -0,0 +1,46 @@
+// which values can be dictionary keys and when two keys are the same one, see Primitive::is_hashable
+
+use rust_glass::errorsystem::error_type::ErrorType;
+use rust_glass::Glass;
+
+use common::{errors, eval};
+
+mod common;
+
+fn unhashable(glass: &mut Glass, source: &str) -> bool {
+    let errors = glass.eval_str(source).unwrap_err();
+    matches!(errors[0].get_error_type(), ErrorType::Unhashable(..))
+}
+
+#[test]
+fn equal_numbers_are_one_key() {
+    let mut glass = Glass::new();
+
+    assert_eq!(eval(&mut glass, "{1: 2, 1.0: 3}").to_string(), "{1: 3}");
+    assert_eq!(eval(&mut glass, "d = {0: \"zero\"}\nd[-0] = \"negative zero\"\nd").to_string(), "{0: negative zero}");
+}
+
+#[test]
+fn nan_and_containers_are_rejected_as_keys() {
+    let mut glass = Glass::new();
+    glass.set_global("nan", f64::NAN);
+
+    assert!(unhashable(&mut glass, "{nan: 1}"));
+    assert!(unhashable(&mut glass, "d = {}\nd[nan] = 1"));
+    assert!(unhashable(&mut glass, "{[1]: 1}"));
+    assert!(unhashable(&mut glass, "{}.get({})"));
+    assert_eq!(errors("{[1]: 1}")[0].get_error_type().get_code(), "G0022");
+}
+
+#[test]
+fn in_is_false_for_unhashable_values() {
+    let mut glass = Glass::new();
+    glass.set_global("nan", f64::NAN);
+
+    assert_eq!(eval(&mut glass, "d = {0: 1}\n[[1] in d, {} in d, nan in d, 0 in d]").to_string(), "[false, false, false, true]");
+}
+
+#[test]
+fn void_is_a_key() {
+    assert_eq!(eval(&mut Glass::new(), "d = {void: 1}\nd[void]").to_string(), "1");
+}
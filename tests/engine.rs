@@ -0,0 +1,258 @@
+// the embedding api, everything a host program does with a Glass session. the first two tests are the
+// examples from the embedding section of the readme
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::rc::Rc;
+
+use rust_glass::errorsystem::error_type::ErrorType;
+use rust_glass::{Arity, Glass, GlassError, Primitive};
+
+use common::eval;
+
+mod common;
+
+fn error_type(error: &GlassError) -> &ErrorType {
+    error.get_error_type()
+}
+
+#[test]
+fn readme_calls_a_glass_function() {
+    let mut glass = Glass::new();
+    glass.set_global("rate", 0.2);
+    glass.eval_str("func tax(amount) => amount * rate").unwrap();
+
+    let tax = glass.call_function("tax", vec![150.into()]).unwrap();
+    assert_eq!(f64::try_from(tax).unwrap(), 30.0);
+}
+
+#[test]
+fn readme_registers_a_rust_function() {
+    let mut glass = Glass::new();
+
+    glass.register_function("shout", Arity::Exact(1), |args| {
+        Ok(Primitive::from(args[0].to_string().to_uppercase()))
+    });
+    glass.eval_str("println(shout(\"hi\"))").unwrap();
+
+    assert_eq!(String::try_from(eval(&mut glass, "shout(\"hi\")")).unwrap(), "HI");
+}
+
+#[test]
+fn eval_str_returns_the_value_of_the_last_statement() {
+    let mut glass = Glass::new();
+
+    assert_eq!(f64::try_from(eval(&mut glass, "x = 2\nx * 21")).unwrap(), 42.0);
+    assert!(matches!(eval(&mut glass, "y = 1"), Primitive::Void));
+}
+
+#[test]
+fn evaluations_share_the_global_scope() {
+    let mut glass = Glass::new();
+
+    eval(&mut glass, "count = 1");
+    eval(&mut glass, "func bump() => count + 1");
+    assert_eq!(f64::try_from(eval(&mut glass, "bump()")).unwrap(), 2.0);
+}
+
+#[test]
+fn eval_str_returns_every_syntax_error() {
+    let errors = Glass::new().eval_str("x = 1 +\ny = 2\nz = * 3").unwrap_err();
+
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors.iter().all(|error| error.get_position().map(|pos| pos.get_filename()) == Some("<string>")));
+}
+
+#[test]
+fn eval_str_returns_the_runtime_error() {
+    let errors = Glass::new().eval_str("x = 1 / 0").unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(error_type(&errors[0]), ErrorType::DivisionByZero));
+}
+
+#[test]
+fn eval_file_reports_errors_against_the_file() {
+    let path = std::env::temp_dir().join(format!("rust_glass_engine_test_{}.glass", std::process::id()));
+    fs::write(&path, "answer = 42\nanswer / 0").unwrap();
+
+    let mut glass = Glass::new();
+    let errors = glass.eval_file(&path).unwrap_err();
+    fs::remove_file(&path).unwrap();
+
+    let filename = errors[0].get_position().unwrap().get_filename();
+    assert_eq!(filename, path.to_string_lossy());
+    assert_eq!(f64::try_from(glass.get_global("answer").unwrap()).unwrap(), 42.0);
+}
+
+#[test]
+fn eval_file_of_a_missing_file_fails() {
+    let errors = Glass::new().eval_file("this/file/does/not/exist.glass").unwrap_err();
+
+    assert!(matches!(error_type(&errors[0]), ErrorType::UnknownFile(..)));
+}
+
+#[test]
+fn globals_are_shared_with_the_host() {
+    let mut glass = Glass::new();
+
+    glass.set_global("items", vec![1, 2]);
+    eval(&mut glass, "items.push(3)");
+    assert_eq!(Vec::<f64>::try_from(glass.get_global("items").unwrap()).unwrap(), vec![1.0, 2.0, 3.0]);
+
+    // lists are shared, so a change made by the host is seen by glass
+    if let Some(Primitive::List(items)) = glass.get_global("items") {
+        items.borrow_mut().push(Primitive::from(4));
+    }
+    assert_eq!(f64::try_from(eval(&mut glass, "len(items)")).unwrap(), 4.0);
+
+    assert!(glass.get_global("missing").is_none());
+}
+
+#[test]
+fn set_global_replaces_the_value() {
+    let mut glass = Glass::new();
+
+    glass.set_global("name", "glass");
+    glass.set_global("name", "rust");
+    assert_eq!(String::try_from(eval(&mut glass, "name.upper()")).unwrap(), "RUST");
+}
+
+#[test]
+fn call_function_calls_glass_and_native_functions() {
+    let mut glass = Glass::new();
+    eval(&mut glass, "add = function(a, b) => a + b");
+
+    let sum = glass.call_function("add", vec![Primitive::from(1), Primitive::from(2)]).unwrap();
+    assert_eq!(f64::try_from(sum).unwrap(), 3.0);
+
+    let length = glass.call_function("len", vec![Primitive::from("glass")]).unwrap();
+    assert_eq!(f64::try_from(length).unwrap(), 5.0);
+}
+
+#[test]
+fn call_function_errors() {
+    let mut glass = Glass::new();
+    eval(&mut glass, "func fail(x) => x / 0\nvalue = 1");
+
+    let missing = glass.call_function("missing", vec![]).unwrap_err();
+    assert!(matches!(error_type(&missing), ErrorType::NoDefiningScope(..)));
+
+    let arg_count = glass.call_function("fail", vec![]).unwrap_err();
+    assert!(matches!(error_type(&arg_count), ErrorType::UnexpectedArgCount(1, 0)));
+
+    let runtime = glass.call_function("fail", vec![Primitive::from(1)]).unwrap_err();
+    assert!(matches!(error_type(&runtime), ErrorType::DivisionByZero));
+    assert_eq!(runtime.get_traceback().map(|frames| frames.len()), Some(1));
+
+    let not_callable = glass.call_function("value", vec![]).unwrap_err();
+    assert!(matches!(error_type(&not_callable), ErrorType::InvalidCall(..)));
+}
+
+#[test]
+fn register_function_checks_the_arity_before_calling() {
+    let calls = Rc::new(Cell::new(0));
+    let counter = Rc::clone(&calls);
+    let mut glass = Glass::new();
+
+    glass.register_function("pair", Arity::Between(1, 2), move |args| {
+        counter.set(counter.get() + 1);
+        Ok(Primitive::from(args))
+    });
+
+    assert_eq!(Vec::<f64>::try_from(eval(&mut glass, "pair(1, 2)")).unwrap(), vec![1.0, 2.0]);
+
+    let errors = glass.eval_str("pair()").unwrap_err();
+    assert!(matches!(error_type(&errors[0]), ErrorType::UnexpectedArgCount(1, 0)));
+    assert!(glass.eval_str("pair(1, 2, 3)").is_err());
+    assert_eq!(calls.get(), 1);
+}
+
+#[test]
+fn register_function_errors_are_catchable() {
+    let mut glass = Glass::new();
+
+    glass.register_function("fail", Arity::Exact(0), |_| {
+        Err(GlassError::new(ErrorType::Native(String::from("host failure")), None))
+    });
+
+    let message = eval(&mut glass, "try =>\n    fail()\ncatch err =>\n    message = err.message\nend\nmessage");
+    assert_eq!(String::try_from(message).unwrap(), "host failure");
+
+    let errors = glass.eval_str("fail()").unwrap_err();
+    assert!(matches!(error_type(&errors[0]), ErrorType::Native(message) if message == "host failure"));
+    assert_eq!(error_type(&errors[0]).get_code(), "G0039");
+    assert_eq!(errors[0].get_position().unwrap().get_row(), 1);
+}
+
+#[test]
+fn rust_values_convert_to_primitives() {
+    assert!(matches!(Primitive::from(1.5), Primitive::Number(value) if value == 1.5));
+    assert!(matches!(Primitive::from(-3i32), Primitive::Number(value) if value == -3.0));
+    assert!(matches!(Primitive::from(3i64), Primitive::Number(value) if value == 3.0));
+    assert!(matches!(Primitive::from(3usize), Primitive::Number(value) if value == 3.0));
+    assert!(matches!(Primitive::from(true), Primitive::Bool(true)));
+    assert!(matches!(Primitive::from("glass"), Primitive::String(value) if value == "glass"));
+    assert!(matches!(Primitive::from(String::from("glass")), Primitive::String(value) if value == "glass"));
+    assert!(matches!(Primitive::from(()), Primitive::Void));
+    assert!(matches!(Primitive::from(None::<f64>), Primitive::Void));
+    assert!(matches!(Primitive::from(Some(1)), Primitive::Number(value) if value == 1.0));
+
+    let list = Primitive::from(vec![vec![1, 2], vec![]]);
+    assert_eq!(list.to_string(), "[[1, 2], []]");
+
+    let mut entries = HashMap::new();
+    entries.insert(String::from("a"), 1);
+    assert_eq!(Primitive::from(entries).to_string(), "{a: 1}");
+}
+
+#[test]
+fn primitives_convert_to_rust_values() {
+    let mut glass = Glass::new();
+
+    assert_eq!(f64::try_from(eval(&mut glass, "0.5")).unwrap(), 0.5);
+    assert!(bool::try_from(eval(&mut glass, "1 < 2")).unwrap());
+    assert_eq!(String::try_from(eval(&mut glass, "\"a\" + \"b\"")).unwrap(), "ab");
+    assert!(<()>::try_from(eval(&mut glass, "void")).is_ok());
+    assert_eq!(Vec::<String>::try_from(eval(&mut glass, "\"a b\".split()")).unwrap(), vec!["a", "b"]);
+
+    let entries = HashMap::<String, Vec<f64>>::try_from(eval(&mut glass, "{\"a\": [1], \"b\": []}")).unwrap();
+    assert_eq!(entries["a"], vec![1.0]);
+    assert!(entries["b"].is_empty());
+}
+
+#[test]
+fn failed_conversions_name_both_types() {
+    let mut glass = Glass::new();
+
+    let error = f64::try_from(Primitive::from("1")).unwrap_err();
+    assert!(matches!(error_type(&error), ErrorType::UnexpectedType("num", "str")));
+
+    // a single item of the wrong type fails the whole conversion
+    assert!(Vec::<f64>::try_from(eval(&mut glass, "[1, \"2\"]")).is_err());
+    assert!(HashMap::<String, f64>::try_from(eval(&mut glass, "{1: 1}")).is_err());
+    assert!(bool::try_from(Primitive::Void).is_err());
+}
+
+#[test]
+fn dropping_a_session_frees_its_globals() {
+    let data = Primitive::new_list(vec![Primitive::from(1)]);
+    let mut glass = Glass::new();
+
+    glass.set_global("data", data.clone());
+    glass.eval_str("func first() => data[0]\nadd = function(a, b) => a + b").unwrap();
+    drop(glass);
+
+    match data {
+        Primitive::List(items) => assert_eq!(Rc::strong_count(&items), 1),
+        value => panic!("{:?} is not a list", value),
+    }
+}
+
+#[test]
+fn empty_string_evaluates_to_void() {
+    assert!(matches!(Glass::new().eval_str(""), Ok(Primitive::Void)));
+    assert!(matches!(Glass::new().eval_str("\n\n"), Ok(Primitive::Void)));
+}
@@ -0,0 +1,25 @@
+// every error code can be looked up with --explain
+
+use rust_glass::errorsystem::explain::get_explanation;
+
+// the newest code, bump it along with ErrorType::get_code
+const LAST_CODE: usize = 40;
+
+#[test]
+fn every_code_has_an_explanation() {
+    for number in 1..=LAST_CODE {
+        let code = format!("G{:04}", number);
+        assert!(get_explanation(&code).is_some(), "{} has no explanation", code);
+    }
+}
+
+#[test]
+fn unknown_codes_have_no_explanation() {
+    assert!(get_explanation(&format!("G{:04}", LAST_CODE + 1)).is_none());
+    assert!(get_explanation("G0000").is_none());
+}
+
+#[test]
+fn codes_are_case_insensitive() {
+    assert_eq!(get_explanation("g0020"), get_explanation("G0020"));
+}
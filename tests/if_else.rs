@@ -0,0 +1,48 @@
+// if / else if / else chains, written with single line bodies, blocks or a mix of both
+
+use rust_glass::Glass;
+
+use common::eval;
+
+mod common;
+
+fn branch(x: i32, source: &str) -> String {
+    let mut glass = Glass::new();
+    glass.set_global("x", x);
+    eval(&mut glass, source).to_string()
+}
+
+#[test]
+fn else_can_follow_a_single_line_branch_on_the_next_line() {
+    let source = "
+if x == 1 => result = \"one\"
+else if x == 2 => result = \"two\"
+
+else => result = \"other\"
+result";
+
+    assert_eq!(branch(1, source), "one");
+    assert_eq!(branch(2, source), "two");
+    assert_eq!(branch(3, source), "other");
+}
+
+#[test]
+fn single_line_and_block_branches_mix() {
+    let source = "
+if x == 1 =>
+    result = \"one\"
+else if x == 2 => result = \"two\"
+else =>
+    result = \"other\"
+end
+result";
+
+    assert_eq!(branch(1, source), "one");
+    assert_eq!(branch(2, source), "two");
+    assert_eq!(branch(3, source), "other");
+}
+
+#[test]
+fn an_if_without_else_ends_at_its_line() {
+    assert_eq!(branch(1, "result = 0\nif x == 2 => result = 2\nresult += 1\nresult"), "1");
+}
@@ -0,0 +1,73 @@
+// --error-format=json, checked through the binary since that's the interface tools consume. every error
+// is a single line object
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+// runs the source as a file named after the test (tests run in parallel) and returns the reported lines
+fn json_errors(name: &str, source: &str) -> (String, Vec<String>) {
+    let path = env::temp_dir().join(format!("glass_json_{}.glass", name));
+    fs::write(&path, source).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rust_glass")).arg("--error-format=json").arg(&path).output().unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    (path.display().to_string(), stderr.lines().map(String::from).collect())
+}
+
+fn span(filename: &str, line_start: usize, column_start: usize, line_end: usize, column_end: usize) -> String {
+    format!(
+        "\"filename\":\"{}\",\"line_start\":{},\"column_start\":{},\"line_end\":{},\"column_end\":{}",
+        filename, line_start, column_start, line_end, column_end,
+    )
+}
+
+#[test]
+fn strings_are_escaped() {
+    let (filename, errors) = json_errors("escaped", "throw \"say \\\"hi\\\"\\n\\tback\\\\slash\"");
+
+    assert_eq!(errors, vec![format!(
+        "{{\"code\":\"G0031\",\"severity\":\"error\",\"message\":\"Error: say \\\"hi\\\"\\n\\tback\\\\slash\",{},\"labels\":[],\"help\":null,\"notes\":[],\"traceback\":[]}}",
+        span(&filename, 1, 1, 1, 6),
+    )]);
+}
+
+#[test]
+fn errors_without_a_position_have_null_spans() {
+    let output = Command::new(env!("CARGO_BIN_EXE_rust_glass")).arg("--error-format=json").arg("missing.glass").output().unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(stderr.starts_with("{\"code\":\"G0004\""), "{}", stderr);
+    assert!(stderr.contains("\"filename\":null,\"line_start\":null,\"column_start\":null,\"line_end\":null,\"column_end\":null"), "{}", stderr);
+}
+
+#[test]
+fn labels_have_their_own_spans() {
+    let (filename, errors) = json_errors("labels", "x = \"abc\ny = 1");
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].contains(&format!("\"labels\":[{{\"message\":\"string started here\",{}}}]", span(&filename, 1, 5, 1, 6))), "{}", errors[0]);
+}
+
+// the end of the file is past the last character, it still spans one column
+#[test]
+fn spans_at_the_end_of_the_file_are_one_column_wide() {
+    let (filename, errors) = json_errors("end_of_file", "println(q\"");
+
+    assert!(errors[0].contains(&span(&filename, 1, 11, 1, 12)), "{}", errors[0]);
+}
+
+#[test]
+fn the_traceback_lists_calls_outermost_first() {
+    let (filename, errors) = json_errors("traceback", "func f() => g()\nfunc g() => 1 / 0\nf()");
+
+    assert!(errors[0].contains(&span(&filename, 2, 15, 2, 16)), "{}", errors[0]);
+    assert!(errors[0].ends_with(&format!(
+        "\"traceback\":[{{\"function\":\"f\",{}}},{{\"function\":\"g\",{}}}]}}",
+        span(&filename, 3, 2, 3, 3),
+        span(&filename, 1, 14, 1, 15),
+    )), "{}", errors[0]);
+}
@@ -0,0 +1,50 @@
+// string interpolation is split up by the lexer, see Lexer::consume_string for the tokens it produces
+
+use rust_glass::errorsystem::error_type::ErrorType;
+use rust_glass::errorsystem::GlassError;
+use rust_glass::lang::lexer::Lexer;
+
+fn tokens(source: &str) -> String {
+    let tokens = Lexer::new("<test>", source).lex().unwrap();
+    tokens.iter().map(|token| token.to_string()).collect::<Vec<String>>().join(" ")
+}
+
+fn error(source: &str) -> GlassError {
+    Lexer::new("<test>", source).lex().err().unwrap()
+}
+
+#[test]
+fn escaped_braces_are_literal() {
+    assert_eq!(tokens("\"a\\{b}\""), "String(a{b}) Newline");
+}
+
+#[test]
+fn interpolations_can_hold_strings_and_dictionaries() {
+    assert_eq!(
+        tokens("\"a{\"b{1}\"}c\""),
+        "InterpolationStart String(a) Lbrace InterpolationStart String(b) Lbrace Number(1) Rbrace String InterpolationEnd Rbrace String(c) InterpolationEnd Newline",
+    );
+    assert_eq!(
+        tokens("\"{ {1: 2}[1] }\""),
+        "InterpolationStart String Lbrace Lbrace Number(1) Colon Number(2) Rbrace Lbracket Number(1) Rbracket Rbrace String InterpolationEnd Newline",
+    );
+}
+
+// the brace swallows the rest of the string, including the quote meant to close it
+#[test]
+fn unclosed_interpolations_are_reported_at_the_brace() {
+    for (source, column) in [("println(\"{\")", 10), ("x = \"a {b\"", 8), ("x = \"{\n1 + 1\n", 6)] {
+        let error = error(source);
+
+        assert!(matches!(error.get_error_type(), ErrorType::UnclosedInterpolation), "{}", source);
+        assert_eq!((error.get_position().unwrap().get_row(), error.get_position().unwrap().get_column()), (1, column), "{}", source);
+    }
+}
+
+#[test]
+fn unclosed_strings_are_reported_at_the_end_of_the_file() {
+    let error = error("x = \"abc");
+
+    assert!(matches!(error.get_error_type(), ErrorType::UnclosedString));
+    assert_eq!(error.get_position().unwrap().get_column(), 9);
+}
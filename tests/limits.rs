@@ -0,0 +1,62 @@
+// deeply nested source and values have to fail with an error (or just work) rather than overflow the
+// native stack, which aborts the process. these run on the test harness's small thread stacks
+
+use rust_glass::errorsystem::error_type::ErrorType;
+use rust_glass::Glass;
+
+use common::{errors, eval};
+
+mod common;
+
+fn nested_parentheses(depth: usize) -> String {
+    format!("x = {}1{}", "(".repeat(depth), ")".repeat(depth))
+}
+
+#[test]
+fn deeply_nested_expressions_are_rejected() {
+    let sources = [
+        nested_parentheses(20_000),
+        format!("x = {}1", "- ".repeat(50_000)),
+        format!("x = {}1{}", "[".repeat(20_000), "]".repeat(20_000)),
+    ];
+
+    for source in sources {
+        let errors = errors(&source);
+        assert!(matches!(errors[0].get_error_type(), ErrorType::NestingLimit(_)), "{:?}", errors);
+    }
+}
+
+#[test]
+fn deeply_nested_blocks_are_rejected() {
+    let source = format!("{}x = 1\n{}", "if true =>\n".repeat(1_000), "end\n".repeat(1_000));
+    let errors = errors(&source);
+
+    assert!(matches!(errors[0].get_error_type(), ErrorType::NestingLimit(_)), "{:?}", errors);
+}
+
+#[test]
+fn nesting_below_the_limit_is_allowed() {
+    let mut glass = Glass::new();
+
+    eval(&mut glass, &nested_parentheses(190));
+    let source = format!("{}y = 1\n{}", "if true =>\n".repeat(90), "end\n".repeat(90));
+    eval(&mut glass, &source);
+}
+
+#[test]
+fn deeply_nested_values_are_printed_compared_and_dropped() {
+    let mut glass = Glass::new();
+    let length = eval(&mut glass, "
+a = [1]
+b = [1]
+for i = 0 to 100000 =>
+    a = [a]
+    b = [b]
+end
+d = {}
+for i = 0 to 100000 => d = {\"next\": d}
+[len(\"{a}\"), a == b, a == [1], len(\"{d}\")]");
+
+    assert_eq!(length.to_string(), "[200003, true, false, 800002]");
+    drop(glass);
+}
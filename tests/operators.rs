@@ -0,0 +1,200 @@
+// locks down the precedence and associativity of every operator, see the table above BINARY_OPERATORS
+// in the parser. each case is written so that any other grouping evaluates to a different value (or an
+// error)
+
+use rust_glass::{Glass, Primitive};
+
+use common::{errors, eval};
+
+mod common;
+
+fn num(source: &str) -> f64 {
+    match eval(&mut Glass::new(), source) {
+        Primitive::Number(value) => value,
+        value => panic!("'{}' evaluated to {:?} instead of a number", source, value),
+    }
+}
+
+fn boolean(source: &str) -> bool {
+    match eval(&mut Glass::new(), source) {
+        Primitive::Bool(value) => value,
+        value => panic!("'{}' evaluated to {:?} instead of a bool", source, value),
+    }
+}
+
+fn fails(source: &str) -> bool {
+    Glass::new().eval_str(source).is_err()
+}
+
+#[test]
+fn pow_is_right_associative() {
+    assert_eq!(num("2 ** 3 ** 2"), 512.0);
+    assert_eq!(num("(2 ** 3) ** 2"), 64.0);
+}
+
+#[test]
+fn pow_binds_tighter_than_unary_minus() {
+    assert_eq!(num("-2 ** 2"), -4.0);
+    assert_eq!(num("(-2) ** 2"), 4.0);
+    assert_eq!(num("+2 ** 2"), 4.0);
+    assert!(boolean("-2 ** 2 == -4"));
+}
+
+#[test]
+fn pow_takes_a_unary_exponent() {
+    assert_eq!(num("2 ** -1"), 0.5);
+    assert_eq!(num("2 ** -1 ** 2"), 0.5);
+}
+
+#[test]
+fn pow_binds_tighter_than_multiplication() {
+    assert_eq!(num("2 * 3 ** 2"), 18.0);
+    assert_eq!(num("3 ** 2 * 2"), 18.0);
+    assert_eq!(num("2 ** 3 % 3"), 2.0);
+}
+
+#[test]
+fn pow_assignment() {
+    assert_eq!(num("x = 3\nx **= 2\nx"), 9.0);
+}
+
+#[test]
+fn unary_minus_binds_tighter_than_multiplication() {
+    assert_eq!(num("-2 * 3"), -6.0);
+    assert_eq!(num("2 * -3"), -6.0);
+    assert_eq!(num("- -2"), 2.0);
+    assert_eq!(num("-(2 + 3)"), -5.0);
+}
+
+#[test]
+fn multiplicative_operators_are_left_associative() {
+    assert_eq!(num("100 / 10 / 5"), 2.0);
+    assert_eq!(num("2 * 9 / 3"), 6.0);
+    assert_eq!(num("12 / 3 * 2"), 8.0);
+    assert_eq!(num("17 % 10 % 4"), 3.0);
+    assert_eq!(num("20 % 7 * 2"), 12.0);
+}
+
+#[test]
+fn multiplicative_operators_bind_tighter_than_additive() {
+    assert_eq!(num("2 + 3 * 4"), 14.0);
+    assert_eq!(num("2 * 3 + 4"), 10.0);
+    assert_eq!(num("10 - 6 / 2"), 7.0);
+    assert_eq!(num("10 - 7 % 4"), 7.0);
+    assert_eq!(num("(2 + 3) * 4"), 20.0);
+}
+
+#[test]
+fn additive_operators_are_left_associative() {
+    assert_eq!(num("10 - 4 - 3"), 3.0);
+    assert_eq!(num("10 - 4 + 3"), 9.0);
+    assert_eq!(num("10 + 4 - 3"), 11.0);
+}
+
+#[test]
+fn additive_operators_bind_tighter_than_comparisons() {
+    assert!(boolean("1 + 1 < 3"));
+    assert!(boolean("3 > 1 + 1"));
+    assert!(boolean("2 <= 1 + 1"));
+    assert!(boolean("1 + 1 >= 2"));
+    assert!(boolean("1 + 1 in [2]"));
+    assert!(boolean("1 + 1 is num"));
+}
+
+#[test]
+fn comparisons_do_not_chain() {
+    // `(1 < 2) < 3` compares a bool with a number
+    assert!(fails("1 < 2 < 3"));
+    assert!(boolean("1 < 2 == 2 < 3"));
+}
+
+#[test]
+fn comparisons_bind_tighter_than_equality() {
+    assert!(boolean("1 < 2 == true"));
+    assert!(boolean("true == 1 < 2"));
+    assert!(boolean("1 > 2 != true"));
+    assert!(boolean("2 in [2] == true"));
+    assert!(boolean("2 is num != false"));
+}
+
+#[test]
+fn equality_is_left_associative() {
+    assert!(boolean("1 == 1 == true"));
+    assert!(boolean("1 == 2 == false"));
+    assert!(boolean("1 != 2 != false"));
+}
+
+#[test]
+fn is_not_negates_the_check() {
+    assert!(boolean("1 is not str"));
+    assert!(!boolean("1 is not num"));
+    assert!(boolean("1 is not str == true"));
+}
+
+#[test]
+fn not_binds_looser_than_equality() {
+    assert!(boolean("not 1 == 2"));
+    assert!(boolean("not 1 > 2"));
+    assert!(boolean("not 3 in [1, 2]"));
+    assert!(boolean("not not true"));
+}
+
+#[test]
+fn not_binds_tighter_than_and() {
+    assert!(!boolean("not true and false"));
+    assert!(boolean("not false and true"));
+    assert!(boolean("true and not false"));
+}
+
+#[test]
+fn and_binds_tighter_than_or() {
+    assert!(boolean("true or true and false"));
+    assert!(boolean("false and false or true"));
+    assert!(!boolean("(true or true) and false"));
+}
+
+#[test]
+fn and_or_are_left_associative() {
+    assert_eq!(num("1 and 2 and 3"), 3.0);
+    assert_eq!(num("0 or 0 or 3"), 3.0);
+    assert_eq!(num("0 and 1 or 2"), 2.0);
+}
+
+#[test]
+fn and_or_bind_looser_than_equality() {
+    assert!(boolean("1 == 1 and 2 == 2"));
+    assert!(boolean("1 == 2 or 2 == 2"));
+}
+
+#[test]
+fn and_or_short_circuit_and_return_the_deciding_operand() {
+    assert!(!boolean("false and undefined"));
+    assert!(boolean("true or undefined"));
+    assert!(fails("true and undefined"));
+    assert_eq!(eval(&mut Glass::new(), "0 or \"default\""), Primitive::from("default"));
+    assert_eq!(num("2 and 5"), 5.0);
+}
+
+#[test]
+fn postfix_binds_tightest() {
+    assert_eq!(num("-[2][0] ** 2"), -4.0);
+    assert_eq!(num("[1, 2, 3].len() * 2"), 6.0);
+    assert_eq!(num("func f() => 3\n-f() ** 2"), -9.0);
+    assert!(boolean("not [false][0]"));
+}
+
+#[test]
+fn compound_assignment_takes_a_whole_expression() {
+    assert_eq!(num("x = 2\nx *= 1 + 2\nx"), 6.0);
+    assert_eq!(num("x = 2\nx **= 1 + 1\nx"), 4.0);
+}
+
+#[test]
+fn undefined_operators_are_reported_as_written() {
+    let cases = [("true + 1", "+"), ("\"a\" < 1", "<"), ("[1] * \"a\"", "*"), ("\"a\" ** 2", "**"), ("[true, 1].sort()", "<")];
+
+    for (source, symbol) in cases {
+        let message = errors(source)[0].get_error_type().to_string();
+        assert_eq!(message, format!("Cannot use operator '{}' on this type", symbol), "{}", source);
+    }
+}
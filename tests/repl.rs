@@ -0,0 +1,54 @@
+// the REPL, driven through the binary's stdin the same way a terminal would
+
+use std::env;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// the prompts are left out, only what the lines printed remains
+fn run(input: &str) -> (String, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rust_glass"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap().replace("... ", "").replace("> ", "");
+    (stdout.trim().to_string(), String::from_utf8(output.stderr).unwrap())
+}
+
+#[test]
+fn blocks_continue_until_they_are_closed() {
+    let (stdout, stderr) = run("for i = 0 to 3 =>\n    println(i)\nend\n");
+
+    assert_eq!(stdout, "0\n1\n2");
+    assert_eq!(stderr, "");
+}
+
+#[test]
+fn a_blank_line_cancels_a_block() {
+    let (stdout, stderr) = run("if true =>\n    println(\"never\")\n\nprintln(\"after\")\n");
+
+    assert_eq!(stdout, "after");
+    assert_eq!(stderr, "");
+}
+
+#[test]
+fn the_end_of_input_discards_an_unfinished_block() {
+    let (stdout, stderr) = run("x = 1\nwhile true =>\n    x += 1\n");
+
+    assert_eq!(stdout, "");
+    assert_eq!(stderr, "");
+}
+
+#[test]
+fn errors_dont_end_the_session() {
+    let (stdout, stderr) = run("x = 1 / 0\nx = 2\nx\n");
+
+    assert_eq!(stdout, "2");
+    assert!(stderr.contains("G0005"), "{}", stderr);
+}
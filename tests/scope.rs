@@ -0,0 +1,118 @@
+// `x = value` sets a variable of the current scope (the function call's, or the global one outside of
+// functions) and never an enclosing one. `x += value` updates the closest scope that defines x
+
+use rust_glass::Glass;
+
+use common::{eval, global};
+
+mod common;
+
+#[test]
+fn locals_do_not_overwrite_builtins() {
+    let mut glass = Glass::new();
+    let length = eval(&mut glass, "
+func count(items) =>
+    len = 0
+    for x in items => len += 1
+    return len
+end
+count([1, 2])
+len([1])");
+
+    assert_eq!(length.to_string(), "1");
+}
+
+#[test]
+fn locals_do_not_overwrite_globals() {
+    let mut glass = Glass::new();
+    eval(&mut glass, "
+i = \"global\"
+result = [1]
+func helper() =>
+    result = []
+    for i = 0 to 3 => result.push(i)
+    return result
+end
+local = helper()");
+
+    assert_eq!(global(&glass, "i"), "global");
+    assert_eq!(global(&glass, "result"), "[1]");
+    assert_eq!(global(&glass, "local"), "[0, 1, 2]");
+}
+
+#[test]
+fn caught_errors_are_locals() {
+    let mut glass = Glass::new();
+    eval(&mut glass, "
+err = \"global\"
+func attempt() =>
+    try =>
+        throw \"boom\"
+    catch err =>
+        return err.message
+    end
+end
+message = attempt()");
+
+    assert_eq!(global(&glass, "err"), "global");
+    assert_eq!(global(&glass, "message"), "boom");
+}
+
+#[test]
+fn globals_can_be_read_from_functions() {
+    let mut glass = Glass::new();
+    let value = eval(&mut glass, "
+rate = 2
+func scale(x) => x * rate
+scale(21)");
+
+    assert_eq!(value.to_string(), "42");
+}
+
+#[test]
+fn compound_assignment_writes_through_to_the_enclosing_scope() {
+    let mut glass = Glass::new();
+    let counts = eval(&mut glass, "
+func makeCounter() =>
+    c = 0
+    func increment() =>
+        c += 1
+        return c
+    end
+    return increment
+end
+counter = makeCounter()
+other = makeCounter()
+[counter(), counter(), counter(), other()]");
+
+    assert_eq!(counts.to_string(), "[1, 2, 3, 1]");
+}
+
+#[test]
+fn compound_assignment_updates_globals() {
+    let mut glass = Glass::new();
+    eval(&mut glass, "
+total = 0
+func add(x) => total += x
+add(3)
+add(4)");
+
+    assert_eq!(global(&glass, "total"), "7");
+}
+
+#[test]
+fn plain_assignment_in_a_closure_shadows() {
+    let mut glass = Glass::new();
+    let value = eval(&mut glass, "
+func outer() =>
+    c = 1
+    func inner() =>
+        c = 5
+        return c
+    end
+    return [inner(), c]
+end
+outer()");
+
+    assert_eq!(value.to_string(), "[5, 1]");
+}
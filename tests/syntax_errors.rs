@@ -0,0 +1,30 @@
+// error recovery must leave the parser in the state the rest of the source was written in, so an error
+// inside of a body doesn't hide (or invent) errors in the code after it
+
+use rust_glass::errorsystem::error_type::ErrorType;
+
+use common::errors;
+
+mod common;
+
+#[test]
+fn error_in_function_inside_loop_keeps_the_loop_depth() {
+    let errors = errors("while true =>\n    f = function(x) => x +\n    break\nend");
+
+    assert_eq!(errors.len(), 1);
+    assert!(!matches!(errors[0].get_error_type(), ErrorType::OutsideLoop(..)), "{:?}", errors);
+}
+
+#[test]
+fn error_in_function_body_still_reports_return_outside_function() {
+    let errors = errors("func f(x) => x +\nreturn 5");
+
+    assert!(errors.iter().any(|error| matches!(error.get_error_type(), ErrorType::ReturnOutsideFunction)), "{:?}", errors);
+}
+
+#[test]
+fn error_in_loop_body_still_reports_break_outside_loop() {
+    let errors = errors("for i = 0 to 3 => i +\nbreak");
+
+    assert!(errors.iter().any(|error| matches!(error.get_error_type(), ErrorType::OutsideLoop(..))), "{:?}", errors);
+}
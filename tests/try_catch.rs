@@ -0,0 +1,192 @@
+// try / catch / finally and throw, in particular that finally runs however the try body is left and
+// that caught errors can be inspected and rethrown
+
+use std::convert::TryFrom;
+
+use rust_glass::errorsystem::error_type::ErrorType;
+use rust_glass::{Glass, Primitive};
+
+use common::{eval, global};
+
+mod common;
+
+#[test]
+fn finally_runs_when_returning_from_try() {
+    let mut glass = Glass::new();
+    let result = eval(&mut glass, "
+log = []
+func f() =>
+    try =>
+        return \"try\"
+    finally =>
+        log.push(\"finally\")
+    end
+    return \"after\"
+end
+f()");
+
+    assert_eq!(String::try_from(result).unwrap(), "try");
+    assert_eq!(global(&glass, "log"), "[finally]");
+}
+
+#[test]
+fn finally_runs_when_returning_from_catch() {
+    let mut glass = Glass::new();
+    let result = eval(&mut glass, "
+log = []
+func f() =>
+    try =>
+        throw \"boom\"
+    catch =>
+        return \"catch\"
+    finally =>
+        log.push(\"finally\")
+    end
+end
+f()");
+
+    assert_eq!(String::try_from(result).unwrap(), "catch");
+    assert_eq!(global(&glass, "log"), "[finally]");
+}
+
+#[test]
+fn finally_runs_when_breaking_and_continuing() {
+    let mut glass = Glass::new();
+    eval(&mut glass, "
+log = []
+for i = 0 to 3 =>
+    try =>
+        if i == 0 => continue
+        if i == 2 => break
+        log.push(i)
+    finally =>
+        log.push(\"finally {i}\")
+    end
+end");
+
+    assert_eq!(global(&glass, "log"), "[finally 0, 1, finally 1, finally 2]");
+}
+
+#[test]
+fn finally_runs_when_the_error_isnt_caught() {
+    let mut glass = Glass::new();
+    let errors = glass.eval_str("
+cleaned = false
+try =>
+    x = 1 / 0
+finally =>
+    cleaned = true
+end").unwrap_err();
+
+    assert!(matches!(errors[0].get_error_type(), ErrorType::DivisionByZero));
+    assert_eq!(global(&glass, "cleaned"), "true");
+}
+
+#[test]
+fn caught_errors_describe_the_error() {
+    let mut glass = Glass::new();
+    eval(&mut glass, "
+try =>
+    x = [1, 2][5]
+catch err =>
+    caught = err
+end");
+
+    let caught = glass.get_global("caught").unwrap();
+    assert_eq!(caught.get_field("type").unwrap().to_string(), "OutOfBounds");
+    assert_eq!(caught.get_field("message").unwrap().to_string(), "Index 5 out of bounds for length 2");
+    assert_eq!(caught.get_field("file").unwrap().to_string(), "<string>");
+    assert_eq!(caught.get_field("line").unwrap().to_string(), "3");
+}
+
+#[test]
+fn thrown_strings_become_errors() {
+    let mut glass = Glass::new();
+    eval(&mut glass, "
+try =>
+    throw \"something went wrong\"
+catch err =>
+    kind = err.type
+    message = err.message
+end");
+
+    assert_eq!(global(&glass, "kind"), "Error");
+    assert_eq!(global(&glass, "message"), "something went wrong");
+}
+
+#[test]
+fn thrown_dictionaries_are_caught_as_is() {
+    let mut glass = Glass::new();
+    let same = eval(&mut glass, "
+error = {\"type\": \"ValueError\", \"message\": \"bad value\", \"value\": 3}
+try =>
+    throw error
+catch err =>
+    caught = err
+end
+caught is error");
+
+    assert!(bool::try_from(same).unwrap());
+
+    let errors = glass.eval_str("throw error").unwrap_err();
+    assert!(matches!(errors[0].get_error_type(), ErrorType::Thrown(_)));
+    assert_eq!(errors[0].get_error_type().to_string(), "ValueError: bad value");
+}
+
+#[test]
+fn caught_errors_can_be_rethrown() {
+    let mut glass = Glass::new();
+    eval(&mut glass, "
+log = []
+try =>
+    try =>
+        missing()
+    catch err =>
+        log.push(\"inner\")
+        throw err
+    end
+catch err =>
+    log.push(err.type)
+end");
+
+    assert_eq!(global(&glass, "log"), "[inner, NoDefiningScope]");
+}
+
+#[test]
+fn only_strings_and_dictionaries_can_be_thrown() {
+    let errors = Glass::new().eval_str("throw 5").unwrap_err();
+
+    assert!(matches!(errors[0].get_error_type(), ErrorType::UnexpectedType("str", "num")));
+}
+
+#[test]
+fn the_recursion_limit_is_catchable() {
+    let mut glass = Glass::with_limits(10, 50);
+    eval(&mut glass, "
+func forever(n) => forever(n + 1)
+try =>
+    forever(0)
+catch err =>
+    kind = err.type
+    depth = len(err.traceback)
+end");
+
+    assert_eq!(global(&glass, "kind"), "RecursionLimit");
+    assert_eq!(global(&glass, "depth"), "50");
+
+    // the stack unwound, so the session can call functions again
+    eval(&mut glass, "func double(x) => x * 2");
+    assert_eq!(glass.call_function("double", vec![Primitive::from(4)]).unwrap().to_string(), "8");
+}
+
+#[test]
+fn single_line_bodies_continue_on_the_next_line() {
+    let mut glass = Glass::new();
+    eval(&mut glass, "
+steps = []
+try => throw \"boom\"
+catch err => steps.push(err.message)
+finally => steps.push(\"finally\")");
+
+    assert_eq!(global(&glass, "steps"), "[boom, finally]");
+}